@@ -0,0 +1,222 @@
+//! Procedural macros for `hhmmss_rs`.
+//!
+//! Use them through the `macros` feature of `hhmmss_rs`, which re-exports
+//! [`format_duration!`].
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Expr, LitStr, Token};
+
+/// A single piece of a parsed pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Hours { padded: bool },
+    Minutes { padded: bool },
+    Seconds { padded: bool },
+    Fract { places: u32 },
+    Sign { explicit: bool },
+}
+
+const EXPECTED: &str = "expected one of `h`, `hh`, `m`, `mm`, `s`, `ss`, `f`, `f1`..`f9`, `-`, `+`";
+
+fn parse_pattern(pattern: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().map(|&(_, c)| c) == Some('{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek().map(|&(_, c)| c) == Some('}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' => {
+                return Err(format!(
+                    "unmatched `}}` at offset {}; use `}}}}` for a literal brace",
+                    position
+                ))
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '}')) => break,
+                        Some((_, c)) => name.push(c),
+                        None => {
+                            return Err(format!(
+                                "unclosed placeholder at offset {}; use `{{{{` for a literal brace",
+                                position
+                            ))
+                        }
+                    }
+                }
+                let piece = match name.as_str() {
+                    "h" => Piece::Hours { padded: false },
+                    "hh" => Piece::Hours { padded: true },
+                    "m" => Piece::Minutes { padded: false },
+                    "mm" => Piece::Minutes { padded: true },
+                    "s" => Piece::Seconds { padded: false },
+                    "ss" => Piece::Seconds { padded: true },
+                    "f" => Piece::Fract { places: 9 },
+                    "-" => Piece::Sign { explicit: false },
+                    "+" => Piece::Sign { explicit: true },
+                    _ => match name.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                        Some(places @ 1..=9) => Piece::Fract { places },
+                        _ => {
+                            return Err(format!(
+                                "unknown placeholder `{{{}}}` at offset {}; {}",
+                                name, position, EXPECTED
+                            ))
+                        }
+                    },
+                };
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(piece);
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+struct Input {
+    pattern: LitStr,
+    duration: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let duration = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { pattern, duration })
+    }
+}
+
+fn expand(input: Input) -> syn::Result<TokenStream2> {
+    let pieces = parse_pattern(&input.pattern.value())
+        .map_err(|message| syn::Error::new(input.pattern.span(), message))?;
+    let d = quote!(__hhmmss_duration);
+    let mut fmt = String::new();
+    let mut args = Vec::new();
+    for piece in pieces {
+        match piece {
+            Piece::Literal(text) => fmt.push_str(&text.replace('{', "{{").replace('}', "}}")),
+            Piece::Hours { padded } => {
+                fmt.push_str(if padded { "{:02}" } else { "{}" });
+                args.push(quote!(#d.part_of_hours_abs()));
+            }
+            Piece::Minutes { padded } => {
+                fmt.push_str(if padded { "{:02}" } else { "{}" });
+                args.push(quote!(#d.part_of_minutes_abs()));
+            }
+            Piece::Seconds { padded } => {
+                fmt.push_str(if padded { "{:02}" } else { "{}" });
+                args.push(quote!(#d.part_of_seconds_abs()));
+            }
+            Piece::Fract { places } => {
+                fmt.push_str(&format!("{{:0{}}}", places));
+                let divisor = 10u64.pow(9 - places);
+                args.push(quote!(
+                    (#d.part_of_milliseconds_abs() * 1_000_000
+                        + #d.part_of_microseconds_abs() * 1_000
+                        + #d.part_of_nanoseconds_abs())
+                        / #divisor
+                ));
+            }
+            Piece::Sign { explicit } => {
+                fmt.push_str("{}");
+                let positive = if explicit { "+" } else { "" };
+                args.push(quote!(if #d.is_negative() { "-" } else { #positive }));
+            }
+        }
+    }
+    let duration = input.duration;
+    Ok(quote! {
+        {
+            #[allow(unused_imports)]
+            use ::hhmmss_rs::Hhmmss as _;
+            let #d = &(#duration);
+            ::std::format!(#fmt, #(#args),*)
+        }
+    })
+}
+
+/// Formats a duration with a pattern that is validated at compile time.
+///
+/// The pattern is made of literal text and placeholders which expand into
+/// direct calls on the `Hhmmss` accessors of the duration:
+///
+/// | Placeholder | Output                                     |
+/// |-------------|--------------------------------------------|
+/// | `{h}`       | hours without padding ("1")                |
+/// | `{hh}`      | hours, at least two digits ("01")          |
+/// | `{m}`       | minutes of the hour without padding        |
+/// | `{mm}`      | minutes of the hour, two digits            |
+/// | `{s}`       | seconds of the minute without padding      |
+/// | `{ss}`      | seconds of the minute, two digits          |
+/// | `{f}`       | all nine fractional digits                 |
+/// | `{f1}`–`{f9}` | the leading 1 to 9 fractional digits     |
+/// | `{-}`       | "-" for negative durations, nothing otherwise |
+/// | `{+}`       | "-" for negative durations, "+" otherwise  |
+///
+/// Literal braces are written as `{{` and `}}`. Any other placeholder is a
+/// compile error.
+///
+/// ```ignore
+/// let d = std::time::Duration::new(5025, 678_901_234);
+/// assert_eq!(format_duration!("{-}{hh}:{mm}:{ss}.{f3}", d), "01:23:45.678");
+/// ```
+#[proc_macro]
+pub fn format_duration(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_pattern, Piece};
+
+    #[test]
+    fn test_parse_pattern() {
+        assert_eq!(
+            parse_pattern("{-}{h}:{mm}:{ss}.{f3} {{x}}"),
+            Ok(vec![
+                Piece::Sign { explicit: false },
+                Piece::Hours { padded: false },
+                Piece::Literal(":".to_owned()),
+                Piece::Minutes { padded: true },
+                Piece::Literal(":".to_owned()),
+                Piece::Seconds { padded: true },
+                Piece::Literal(".".to_owned()),
+                Piece::Fract { places: 3 },
+                Piece::Literal(" {x}".to_owned()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_pattern_errors() {
+        assert!(parse_pattern("{hh}:{mn}")
+            .unwrap_err()
+            .contains("`{mn}` at offset 5"));
+        assert!(parse_pattern("{f0}").is_err());
+        assert!(parse_pattern("{f10}").is_err());
+        assert!(parse_pattern("{hh").unwrap_err().contains("unclosed"));
+        assert!(parse_pattern("ss}").unwrap_err().contains("unmatched"));
+    }
+}
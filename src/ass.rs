@@ -0,0 +1,375 @@
+//! Advanced SubStation Alpha (ASS/SSA) timestamps and event editing.
+//!
+//! ASS timestamps are written as "H:MM:SS.cc" with centiseconds. Documents
+//! are edited in place: only the `[Events]` section is parsed, every other
+//! section is written back byte for byte.
+//!
+//! ```
+//! use hhmmss_rs::ass::Ass;
+//!
+//! let source = "[Script Info]\nTitle: Example\n\n[Events]\n\
+//!     Format: Layer, Start, End, Style, Text\n\
+//!     Dialogue: 0,0:00:01.00,0:00:04.50,Default,Hello, world!\n";
+//! let mut ass: Ass = source.parse().unwrap();
+//! ass.shift(&std::time::Duration::from_millis(1505)).unwrap();
+//! assert!(ass
+//!     .to_string()
+//!     .ends_with("Dialogue: 0,0:00:02.51,0:00:06.01,Default,Hello, world!\n"));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::fracts::Precision;
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::round::Rounding;
+use crate::Hhmmss;
+
+/// Writes a duration as an ASS timestamp, rounded to the nearest
+/// centisecond.
+pub struct DisplayAss {
+    pub(crate) parts: DurationParts,
+}
+
+impl fmt::Display for DisplayAss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.parts
+            .display_hmmss_and_fract(Precision::CENTISECONDS)
+            .fmt(f)
+    }
+}
+
+impl DisplayAss {
+    pub(crate) fn new<T: Hhmmss + ?Sized>(duration: &T) -> Self {
+        Self {
+            parts: duration.rounded(Precision::CENTISECONDS, Rounding::HalfUp),
+        }
+    }
+}
+
+/// Parses an ASS timestamp such as "1:23:45.67" into [`DurationParts`].
+///
+/// The hours take one or more digits, the minutes and seconds exactly two
+/// and the centiseconds exactly two. Signs are not accepted.
+pub fn parse_timestamp(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let (_, hours) = cursor.integer(Field::Hours)?;
+    cursor.expect(':')?;
+    let (minutes_pos, minutes) = cursor.two_digits(Field::Minutes)?;
+    cursor.expect(':')?;
+    let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+    check_sexagesimal(minutes_pos, Field::Minutes, minutes)?;
+    check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+    cursor.expect('.')?;
+    let (_, centis) = cursor.two_digits(Field::Fraction)?;
+    cursor.finish()?;
+    let secs = hours
+        .checked_mul(3600)
+        .and_then(|s| s.checked_add(minutes * 60 + seconds))
+        .ok_or(ParseError::Overflow)?;
+    Ok(DurationParts::new(false, secs, centis as u32 * 10_000_000))
+}
+
+/// An event line of the `[Events]` section, such as a `Dialogue:` or
+/// `Comment:` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// The line type, e.g. "Dialogue".
+    pub kind: String,
+    pub start: DurationParts,
+    pub end: DurationParts,
+    /// The values of all other columns of the `Format:` line, in its order.
+    /// The last one is usually the text, which may contain commas.
+    pub fields: Vec<String>,
+}
+
+/// A line of the `[Events]` section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventsLine {
+    Event(Event),
+    /// Any other line, such as the `Format:` line, comments and blank lines,
+    /// kept verbatim without its line ending.
+    Other(String),
+}
+
+/// An ASS or SSA script.
+///
+/// Parse it with [`str::parse`] and write it back with its [`Display`]
+/// implementation. The lines of the `[Events]` section are written with the
+/// line ending of its header.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ass {
+    /// Everything up to and including the `[Events]` header line.
+    head: String,
+    format: Vec<String>,
+    start_column: usize,
+    end_column: usize,
+    pub lines: Vec<EventsLine>,
+    /// Everything from the section after `[Events]` on.
+    tail: String,
+    newline: &'static str,
+    final_newline: bool,
+}
+
+/// What went wrong in an [`AssError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AssErrorKind {
+    /// The script has no `[Events]` section.
+    MissingEvents,
+    /// An event appeared before the `Format:` line.
+    MissingFormat,
+    /// The `Format:` line has no column with this name.
+    MissingColumn(String),
+    /// An event has fewer values than the `Format:` line has columns.
+    MissingFields { expected: usize, found: usize },
+    /// A timestamp could not be parsed. Positions are byte offsets into the
+    /// timestamp.
+    Timestamp(ParseError),
+    /// A shifted time is negative.
+    Negative,
+    /// A shifted time is too large.
+    Overflow,
+}
+
+/// An error pointing at the offending line of an [`Ass`] script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssError {
+    /// The line the error was found on, starting at 1. For errors of
+    /// [`Ass::shift`], this is the line as it would be written.
+    pub line: usize,
+    pub kind: AssErrorKind,
+}
+
+impl fmt::Display for AssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            AssErrorKind::MissingEvents => f.write_str("missing [Events] section"),
+            AssErrorKind::MissingFormat => f.write_str("event before the Format line"),
+            AssErrorKind::MissingColumn(name) => {
+                write!(f, "Format line has no {:?} column", name)
+            }
+            AssErrorKind::MissingFields { expected, found } => {
+                write!(f, "expected {} fields, found {}", expected, found)
+            }
+            AssErrorKind::Timestamp(error) => write!(f, "invalid timestamp: {}", error),
+            AssErrorKind::Negative => f.write_str("time is negative"),
+            AssErrorKind::Overflow => f.write_str("time is too large"),
+        }
+    }
+}
+
+impl std::error::Error for AssError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            AssErrorKind::Timestamp(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+const EVENT_KINDS: [&str; 6] = [
+    "Dialogue", "Comment", "Picture", "Sound", "Movie", "Command",
+];
+
+fn column(format: &[String], name: &str, line: usize) -> Result<usize, AssError> {
+    format
+        .iter()
+        .position(|column| column.eq_ignore_ascii_case(name))
+        .ok_or_else(|| AssError {
+            line,
+            kind: AssErrorKind::MissingColumn(name.to_owned()),
+        })
+}
+
+impl FromStr for Ass {
+    type Err = AssError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut offset = 0;
+        let mut line_number = 0;
+        let mut events_start = None;
+        let mut events_end = s.len();
+        for line in s.split_inclusive('\n') {
+            line_number += 1;
+            let trimmed = line.trim_start_matches('\u{feff}').trim();
+            if events_start.is_none() {
+                if trimmed.eq_ignore_ascii_case("[Events]") {
+                    events_start = Some((offset + line.len(), line_number, line));
+                }
+            } else if trimmed.starts_with('[') {
+                events_end = offset;
+                break;
+            }
+            offset += line.len();
+        }
+        let Some((events_start, header_line, header)) = events_start else {
+            return Err(AssError {
+                line: line_number.max(1),
+                kind: AssErrorKind::MissingEvents,
+            });
+        };
+        let section = &s[events_start..events_end];
+
+        let mut format = Vec::new();
+        let mut columns = None;
+        let mut lines = Vec::new();
+        for (i, line) in section.lines().enumerate() {
+            let line_number = header_line + i + 1;
+            let event = line.split_once(':').filter(|(kind, _)| {
+                EVENT_KINDS
+                    .iter()
+                    .any(|event| kind.trim().eq_ignore_ascii_case(event))
+            });
+            let Some((kind, values)) = event else {
+                if let Some(("Format", value)) = line.split_once(':') {
+                    format = value.split(',').map(|c| c.trim().to_owned()).collect();
+                    columns = Some((
+                        column(&format, "Start", line_number)?,
+                        column(&format, "End", line_number)?,
+                    ));
+                }
+                lines.push(EventsLine::Other(line.to_owned()));
+                continue;
+            };
+            let error = |kind| AssError {
+                line: line_number,
+                kind,
+            };
+            let (start_column, end_column) = columns.ok_or(error(AssErrorKind::MissingFormat))?;
+            let values: Vec<_> = values.trim_start().splitn(format.len(), ',').collect();
+            if values.len() != format.len() {
+                return Err(error(AssErrorKind::MissingFields {
+                    expected: format.len(),
+                    found: values.len(),
+                }));
+            }
+            let timestamp = |column: usize| {
+                parse_timestamp(values[column].trim())
+                    .map_err(|e| error(AssErrorKind::Timestamp(e)))
+            };
+            lines.push(EventsLine::Event(Event {
+                kind: kind.trim().to_owned(),
+                start: timestamp(start_column)?,
+                end: timestamp(end_column)?,
+                fields: values
+                    .iter()
+                    .enumerate()
+                    .filter(|&(column, _)| column != start_column && column != end_column)
+                    .map(|(_, value)| (*value).to_owned())
+                    .collect(),
+            }));
+        }
+        let (start_column, end_column) = columns.unwrap_or((usize::MAX, usize::MAX));
+        Ok(Ass {
+            head: s[..events_start].to_owned(),
+            format,
+            start_column,
+            end_column,
+            lines,
+            tail: s[events_end..].to_owned(),
+            newline: if header.ends_with("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            },
+            final_newline: section.ends_with('\n'),
+        })
+    }
+}
+
+impl fmt::Display for Ass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.head)?;
+        for (i, line) in self.lines.iter().enumerate() {
+            if i != 0 {
+                f.write_str(self.newline)?;
+            }
+            match line {
+                EventsLine::Event(event) => {
+                    write!(f, "{}: ", event.kind)?;
+                    let mut fields = event.fields.iter();
+                    for column in 0..self.format.len().max(event.fields.len() + 2) {
+                        if column != 0 {
+                            f.write_str(",")?;
+                        }
+                        if column == self.start_column {
+                            event.start.display_ass().fmt(f)?;
+                        } else if column == self.end_column {
+                            event.end.display_ass().fmt(f)?;
+                        } else if let Some(field) = fields.next() {
+                            f.write_str(field)?;
+                        }
+                    }
+                }
+                EventsLine::Other(text) => f.write_str(text)?,
+            }
+        }
+        if !self.lines.is_empty() && self.final_newline {
+            f.write_str(self.newline)?;
+        }
+        f.write_str(&self.tail)
+    }
+}
+
+impl Ass {
+    /// Returns the column names of the `Format:` line.
+    pub fn format(&self) -> &[String] {
+        &self.format
+    }
+
+    /// Returns the events of the `[Events]` section.
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.lines.iter().filter_map(|line| match line {
+            EventsLine::Event(event) => Some(event),
+            EventsLine::Other(_) => None,
+        })
+    }
+
+    /// Returns the events of the `[Events]` section for editing.
+    pub fn events_mut(&mut self) -> impl Iterator<Item = &mut Event> {
+        self.lines.iter_mut().filter_map(|line| match line {
+            EventsLine::Event(event) => Some(event),
+            EventsLine::Other(_) => None,
+        })
+    }
+
+    /// Moves every event by `offset`, which may be negative.
+    ///
+    /// Nothing is changed if any event would start before zero.
+    pub fn shift<T: Hhmmss + ?Sized>(&mut self, offset: &T) -> Result<(), AssError> {
+        let offset = offset.to_duration_parts().to_nanos();
+        let first_line = self.head.lines().count() + 1;
+        let shift = |line: usize, t: DurationParts| {
+            let error = |kind| AssError {
+                line: first_line + line,
+                kind,
+            };
+            let nanos = t
+                .to_nanos()
+                .checked_add(offset)
+                .ok_or(error(AssErrorKind::Overflow))?;
+            if nanos < 0 {
+                return Err(error(AssErrorKind::Negative));
+            }
+            DurationParts::from_nanos(nanos).map_err(|_| error(AssErrorKind::Overflow))
+        };
+        let mut times = Vec::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            if let EventsLine::Event(event) = line {
+                times.push((shift(i, event.start)?, shift(i, event.end)?));
+            }
+        }
+        for (event, (start, end)) in self.events_mut().zip(times) {
+            event.start = start;
+            event.end = end;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use crate::ass::{parse_timestamp, Ass, AssError, AssErrorKind};
+use crate::parse::{DurationParts, Field, ParseError, ParseHhmmss};
+use crate::Hhmmss;
+
+const SAMPLE: &str = "\u{feff}[Script Info]\r
+; Script generated by Aegisub\r
+Title: Sample\r
+ScriptType: v4.00+\r
+\r
+[V4+ Styles]\r
+Format: Name, Fontname, Fontsize\r
+Style: Default,Arial,20\r
+\r
+[Events]\r
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\r
+Dialogue: 0,0:00:01.00,0:00:04.50,Default,,0,0,0,,Hello, world!\r
+Comment: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,A comment\r
+Dialogue: 0,1:02:03.04,10:00:00.00,Default,,0,0,0,,{\\i1}Two{\\i0}\\Nlines\r
+\r
+[Fonts]\r
+fontname: x.ttf\r
+";
+
+#[test]
+fn test_fmt_ass() {
+    let d = std::time::Duration::new(5025, 678_000_000);
+    assert_eq!(d.fmt_ass(), "1:23:45.68");
+    assert_eq!(
+        std::time::Duration::new(5025, 674_999_999).fmt_ass(),
+        "1:23:45.67"
+    );
+    assert_eq!(
+        std::time::Duration::new(3599, 995_000_000).fmt_ass(),
+        "1:00:00.00"
+    );
+    assert_eq!(std::time::Duration::new(0, 0).fmt_ass(), "0:00:00.00");
+    assert_eq!(std::time::Duration::new(36_000, 0).fmt_ass(), "10:00:00.00");
+}
+
+#[test]
+fn test_parse_ass_timestamp() {
+    assert_eq!(
+        parse_timestamp("1:23:45.67"),
+        Ok(DurationParts::new(false, 5025, 670_000_000))
+    );
+    assert_eq!(
+        std::time::Duration::parse_ass("0:00:00.01"),
+        Ok(std::time::Duration::from_millis(10))
+    );
+    assert_eq!(
+        parse_timestamp("1:23:45.678"),
+        Err(ParseError::InvalidWidth {
+            position: 8,
+            field: Field::Fraction,
+            width: 3
+        })
+    );
+    assert_eq!(
+        parse_timestamp("1:60:00.00"),
+        Err(ParseError::OutOfRange {
+            position: 2,
+            field: Field::Minutes,
+            value: 60
+        })
+    );
+}
+
+#[test]
+fn test_ass_round_trip() {
+    let ass: Ass = SAMPLE.parse().unwrap();
+    assert_eq!(ass.format().len(), 10);
+    let events: Vec<_> = ass.events().collect();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[0].kind, "Dialogue");
+    assert_eq!(events[0].start, DurationParts::new(false, 1, 0));
+    assert_eq!(events[0].end, DurationParts::new(false, 4, 500_000_000));
+    assert_eq!(events[0].fields.len(), 8);
+    assert_eq!(events[0].fields[7], "Hello, world!");
+    assert_eq!(events[1].kind, "Comment");
+    assert_eq!(ass.to_string(), SAMPLE);
+
+    let lf = SAMPLE.replace('\r', "");
+    let ass: Ass = lf.trim_end().parse().unwrap();
+    assert_eq!(ass.to_string(), lf.trim_end());
+}
+
+#[test]
+fn test_ass_shift() {
+    let mut ass: Ass = SAMPLE.parse().unwrap();
+    ass.shift(&std::time::Duration::from_millis(1234)).unwrap();
+    let shifted = ass.to_string();
+    let (head, rest) = shifted.split_at(shifted.find("[Events]").unwrap());
+    assert!(SAMPLE.starts_with(head));
+    assert!(rest.ends_with("\r\n[Fonts]\r\nfontname: x.ttf\r\n"));
+    assert!(rest.contains("Dialogue: 0,0:00:02.23,0:00:05.73,Default,,0,0,0,,Hello, world!\r\n"));
+    assert!(rest.contains("Comment: 0,0:00:06.23,0:00:07.23,"));
+
+    for event in ass.events_mut() {
+        event.fields[7].push('!');
+    }
+    assert!(ass.to_string().contains(",,Hello, world!!\r\n"));
+
+    let before = ass.clone();
+    assert_eq!(
+        ass.shift(&DurationParts::new(true, 3, 0)),
+        Err(AssError {
+            line: 12,
+            kind: AssErrorKind::Negative
+        })
+    );
+    assert_eq!(ass, before);
+}
+
+#[test]
+fn test_ass_errors() {
+    assert_eq!(
+        "[Script Info]\nTitle: x\n".parse::<Ass>(),
+        Err(AssError {
+            line: 2,
+            kind: AssErrorKind::MissingEvents
+        })
+    );
+    assert_eq!(
+        "[Events]\nDialogue: 0,0:00:01.00,0:00:02.00,x\n".parse::<Ass>(),
+        Err(AssError {
+            line: 2,
+            kind: AssErrorKind::MissingFormat
+        })
+    );
+    assert_eq!(
+        "[Events]\nFormat: Layer, Begin, End, Text\n".parse::<Ass>(),
+        Err(AssError {
+            line: 2,
+            kind: AssErrorKind::MissingColumn("Start".to_owned())
+        })
+    );
+    assert_eq!(
+        "[Events]\nFormat: Layer, Start, End, Text\nDialogue: 0,0:00:01.00\n".parse::<Ass>(),
+        Err(AssError {
+            line: 3,
+            kind: AssErrorKind::MissingFields {
+                expected: 4,
+                found: 2
+            }
+        })
+    );
+    let err = "[Events]\nFormat: Layer, Start, End, Text\nDialogue: 0,0:00:01;00,0:00:02.00,x\n"
+        .parse::<Ass>()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        AssError {
+            line: 3,
+            kind: AssErrorKind::Timestamp(ParseError::UnexpectedChar {
+                position: 7,
+                found: ';'
+            })
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "line 3: invalid timestamp: unexpected character ';' at 7"
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_ass_chrono() {
+    let d = chrono::Duration::milliseconds(-5_025_675);
+    assert_eq!(d.fmt_ass(), "-1:23:45.68");
+    assert_eq!(
+        chrono::Duration::parse_ass("1:23:45.68"),
+        Ok(chrono::Duration::milliseconds(5_025_680))
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_ass_time() {
+    let d = time::Duration::milliseconds(5_025_674);
+    assert_eq!(d.fmt_ass(), "1:23:45.67");
+    assert_eq!(
+        time::Duration::parse_ass("1:23:45.67"),
+        Ok(time::Duration::milliseconds(5_025_670))
+    );
+}
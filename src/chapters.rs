@@ -0,0 +1,268 @@
+//! Video chapter lists as found in video descriptions.
+//!
+//! Every chapter is a line starting with its start time, e.g.
+//! "1:23:45 Outro". When writing a list, all times share one layout: "M:SS"
+//! if the last chapter starts within the first hour and "H:MM:SS"
+//! otherwise.
+//!
+//! ```
+//! use hhmmss_rs::chapters::Chapters;
+//!
+//! let chapters: Chapters = "Tracklist:\n0:00 Intro\n05:30 - Main part\n".parse().unwrap();
+//! let end = std::time::Duration::from_secs(600);
+//! chapters
+//!     .validate(&end, &std::time::Duration::from_secs(10))
+//!     .unwrap();
+//! assert_eq!(chapters.to_string(), "0:00 Intro\n5:30 Main part\n");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+/// Parses a chapter start such as "1:23" or "1:23:45" into
+/// [`DurationParts`].
+///
+/// Without hours the minutes take one or more digits and may exceed 59.
+/// The hours take one or more digits, the minutes and seconds after them
+/// exactly two. Signs and fractions are not accepted.
+pub fn parse_timestamp(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let (_, first) = cursor.integer(Field::Minutes)?;
+    cursor.expect(':')?;
+    let (second_pos, second) = cursor.two_digits(Field::Seconds)?;
+    let secs = if cursor.eat(':') {
+        let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+        check_sexagesimal(second_pos, Field::Minutes, second)?;
+        check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+        first
+            .checked_mul(3600)
+            .and_then(|s| s.checked_add(second * 60 + seconds))
+    } else {
+        check_sexagesimal(second_pos, Field::Seconds, second)?;
+        first.checked_mul(60).and_then(|s| s.checked_add(second))
+    };
+    cursor.finish()?;
+    Ok(DurationParts::new(
+        false,
+        secs.ok_or(ParseError::Overflow)?,
+        0,
+    ))
+}
+
+/// A chapter and the time it starts at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chapter {
+    pub start: DurationParts,
+    pub title: String,
+}
+
+/// A list of chapters.
+///
+/// Parse it with [`str::parse`], which reads every line starting with a
+/// start time as a chapter and skips all other lines, and write it back
+/// with its [`Display`] implementation. Times are truncated to seconds.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Chapters {
+    pub chapters: Vec<Chapter>,
+}
+
+/// What went wrong in a [`ChapterError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChapterErrorKind {
+    /// The chapter has no title.
+    MissingTitle,
+    /// The list has no chapters.
+    Empty,
+    /// The first chapter does not start at zero.
+    FirstNotAtZero,
+    /// The chapter starts before the previous one.
+    NotAscending,
+    /// The chapter is shorter than the minimum length.
+    TooShort,
+    /// The chapter starts at or after the end of the video.
+    AfterEnd,
+}
+
+/// An error pointing at the offending chapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterError {
+    /// The position of the chapter in the list, starting at 1. Zero for
+    /// [`ChapterErrorKind::Empty`].
+    pub chapter: usize,
+    /// The line of the input the error was found on, starting at 1. `None`
+    /// for errors found after parsing.
+    pub line: Option<usize>,
+    pub kind: ChapterErrorKind,
+}
+
+impl fmt::Display for ChapterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.kind != ChapterErrorKind::Empty {
+            write!(f, "chapter {}", self.chapter)?;
+            if let Some(line) = self.line {
+                write!(f, " (line {})", line)?;
+            }
+            f.write_str(": ")?;
+        }
+        match &self.kind {
+            ChapterErrorKind::MissingTitle => f.write_str("missing title"),
+            ChapterErrorKind::Empty => f.write_str("no chapters"),
+            ChapterErrorKind::FirstNotAtZero => f.write_str("first chapter must start at 0:00"),
+            ChapterErrorKind::NotAscending => f.write_str("starts before the previous chapter"),
+            ChapterErrorKind::TooShort => f.write_str("chapter is too short"),
+            ChapterErrorKind::AfterEnd => f.write_str("starts after the end"),
+        }
+    }
+}
+
+impl std::error::Error for ChapterError {}
+
+impl FromStr for Chapters {
+    type Err = ChapterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chapters = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let error = |kind| ChapterError {
+                chapter: chapters.len() + 1,
+                line: Some(i + 1),
+                kind,
+            };
+            let trimmed = line.trim_start();
+            let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+            let Ok(start) = parse_timestamp(&trimmed[..end]) else {
+                continue;
+            };
+            let title = trimmed[end..]
+                .trim_start()
+                .trim_start_matches(['-', '–', '—', ':', '|'])
+                .trim();
+            if title.is_empty() {
+                return Err(error(ChapterErrorKind::MissingTitle));
+            }
+            chapters.push(Chapter {
+                start,
+                title: title.to_owned(),
+            });
+        }
+        Ok(Chapters { chapters })
+    }
+}
+
+impl fmt::Display for Chapters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hours = self
+            .chapters
+            .iter()
+            .any(|chapter| chapter.start.secs >= DurationParts::SECONDS_IN_A_HOUR);
+        for chapter in &self.chapters {
+            if hours {
+                write!(f, "{}", chapter.start.display_hmmss())?;
+            } else {
+                write!(f, "{}", chapter.start.display_mss())?;
+            }
+            writeln!(f, " {}", chapter.title)?;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes the characters with a special meaning in FFmpeg metadata.
+fn escape_ffmetadata(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '=' | ';' | '#' | '\\' | '\n') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl Chapters {
+    /// Checks that there is at least one chapter, that the first one starts
+    /// at zero and that every chapter is at least `min_length` long. The
+    /// last chapter ends at `end`, the length of the video.
+    ///
+    /// YouTube requires a minimum length of 10 seconds.
+    pub fn validate<E: Hhmmss + ?Sized, T: Hhmmss + ?Sized>(
+        &self,
+        end: &E,
+        min_length: &T,
+    ) -> Result<(), ChapterError> {
+        let end = end.to_duration_parts().to_nanos();
+        let min_length = min_length.to_duration_parts().to_nanos();
+        let error = |i: usize, kind| ChapterError {
+            chapter: i + 1,
+            line: None,
+            kind,
+        };
+        let first = self.chapters.first().ok_or(ChapterError {
+            chapter: 0,
+            line: None,
+            kind: ChapterErrorKind::Empty,
+        })?;
+        if !first.start.is_zero() {
+            return Err(error(0, ChapterErrorKind::FirstNotAtZero));
+        }
+        for (i, pair) in self.chapters.windows(2).enumerate() {
+            let length = pair[1].start.to_nanos() - pair[0].start.to_nanos();
+            if length < 0 {
+                return Err(error(i + 1, ChapterErrorKind::NotAscending));
+            }
+            if length < min_length {
+                return Err(error(i, ChapterErrorKind::TooShort));
+            }
+        }
+        let last = self.chapters.len() - 1;
+        let length = end - self.chapters[last].start.to_nanos();
+        if length <= 0 {
+            return Err(error(last, ChapterErrorKind::AfterEnd));
+        }
+        if length < min_length {
+            return Err(error(last, ChapterErrorKind::TooShort));
+        }
+        Ok(())
+    }
+
+    /// Writes the chapters as an FFmpeg `;FFMETADATA1` file in milliseconds,
+    /// for use with `ffmpeg -i video -i chapters.txt -map_metadata 1`.
+    ///
+    /// Every chapter ends where the next one starts and the last one ends at
+    /// `end`, the length of the video.
+    pub fn to_ffmetadata<T: Hhmmss + ?Sized>(&self, end: &T) -> Result<String, ChapterError> {
+        let millis = |d: &DurationParts| d.to_nanos() / 1_000_000;
+        let end = end.to_duration_parts();
+        let mut out = String::from(";FFMETADATA1\n");
+        for (i, chapter) in self.chapters.iter().enumerate() {
+            let (chapter_end, error) = match self.chapters.get(i + 1) {
+                Some(next) => (next.start, (i + 2, ChapterErrorKind::NotAscending)),
+                None => (end, (i + 1, ChapterErrorKind::AfterEnd)),
+            };
+            if chapter_end.to_nanos() <= chapter.start.to_nanos() {
+                let (chapter, kind) = error;
+                return Err(ChapterError {
+                    chapter,
+                    line: None,
+                    kind,
+                });
+            }
+            out.push_str(&format!(
+                "\n[CHAPTER]\nTIMEBASE=1/1000\nSTART={}\nEND={}\ntitle={}\n",
+                millis(&chapter.start),
+                millis(&chapter_end),
+                escape_ffmetadata(&chapter.title)
+            ));
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,196 @@
+use crate::chapters::{parse_timestamp, Chapter, ChapterError, ChapterErrorKind, Chapters};
+use crate::parse::{DurationParts, Field, ParseError};
+
+fn chapter(secs: u64, title: &str) -> Chapter {
+    Chapter {
+        start: DurationParts::new(false, secs, 0),
+        title: title.to_owned(),
+    }
+}
+
+#[test]
+fn test_parse_chapter_timestamp() {
+    assert_eq!(parse_timestamp("0:00"), Ok(DurationParts::new(false, 0, 0)));
+    assert_eq!(
+        parse_timestamp("05:30"),
+        Ok(DurationParts::new(false, 330, 0))
+    );
+    assert_eq!(
+        parse_timestamp("75:00"),
+        Ok(DurationParts::new(false, 4500, 0))
+    );
+    assert_eq!(
+        parse_timestamp("1:23:45"),
+        Ok(DurationParts::new(false, 5025, 0))
+    );
+    assert_eq!(
+        parse_timestamp("1:60:00"),
+        Err(ParseError::OutOfRange {
+            position: 2,
+            field: Field::Minutes,
+            value: 60,
+        })
+    );
+    assert_eq!(
+        parse_timestamp("1:5"),
+        Err(ParseError::InvalidWidth {
+            position: 2,
+            field: Field::Seconds,
+            width: 1,
+        })
+    );
+    assert_eq!(
+        parse_timestamp("1:05.5"),
+        Err(ParseError::UnexpectedChar {
+            position: 4,
+            found: '.',
+        })
+    );
+}
+
+#[test]
+fn test_parse_chapters() {
+    let chapters: Chapters = "Thanks for watching!\n\
+        \n\
+        0:00 Intro\n\
+        \x20 2:05 - Setup\n\
+        12:40 — The long part: details\n\
+        1:02:03 | Outro\n\
+        Follow me elsewhere.\n"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        chapters.chapters,
+        [
+            chapter(0, "Intro"),
+            chapter(125, "Setup"),
+            chapter(760, "The long part: details"),
+            chapter(3723, "Outro"),
+        ]
+    );
+}
+
+#[test]
+fn test_write_chapters() {
+    let mut chapters = Chapters {
+        chapters: vec![
+            chapter(0, "Intro"),
+            chapter(125, "Setup"),
+            chapter(760, "End"),
+        ],
+    };
+    assert_eq!(chapters.to_string(), "0:00 Intro\n2:05 Setup\n12:40 End\n");
+    assert_eq!(
+        chapters.to_string().parse::<Chapters>(),
+        Ok(chapters.clone())
+    );
+
+    chapters.chapters.push(chapter(3723, "Outro"));
+    assert_eq!(
+        chapters.to_string(),
+        "0:00:00 Intro\n0:02:05 Setup\n0:12:40 End\n1:02:03 Outro\n"
+    );
+    assert_eq!(chapters.to_string().parse::<Chapters>(), Ok(chapters));
+
+    let chapters = Chapters {
+        chapters: vec![Chapter {
+            start: DurationParts::new(false, 59, 999_999_999),
+            title: "Truncated".to_owned(),
+        }],
+    };
+    assert_eq!(chapters.to_string(), "0:59 Truncated\n");
+}
+
+#[test]
+fn test_validate_chapters() {
+    let ten = std::time::Duration::from_secs(10);
+    let end = std::time::Duration::from_secs(600);
+    let chapters = |starts: &[u64]| Chapters {
+        chapters: starts.iter().map(|&s| chapter(s, "x")).collect(),
+    };
+    assert_eq!(chapters(&[0, 10, 100]).validate(&end, &ten), Ok(()));
+    assert_eq!(
+        chapters(&[]).validate(&end, &ten),
+        Err(ChapterError {
+            chapter: 0,
+            line: None,
+            kind: ChapterErrorKind::Empty,
+        })
+    );
+    assert_eq!(
+        chapters(&[5, 20]).validate(&end, &ten).unwrap_err().kind,
+        ChapterErrorKind::FirstNotAtZero
+    );
+    let error = chapters(&[0, 30, 20]).validate(&end, &ten).unwrap_err();
+    assert_eq!(
+        (error.chapter, error.kind),
+        (3, ChapterErrorKind::NotAscending)
+    );
+    let error = chapters(&[0, 30, 39]).validate(&end, &ten).unwrap_err();
+    assert_eq!(error.to_string(), "chapter 2: chapter is too short");
+    assert_eq!((error.chapter, error.kind), (2, ChapterErrorKind::TooShort));
+    let error = chapters(&[0, 30, 595]).validate(&end, &ten).unwrap_err();
+    assert_eq!((error.chapter, error.kind), (3, ChapterErrorKind::TooShort));
+    assert_eq!(chapters(&[0, 30, 590]).validate(&end, &ten), Ok(()));
+    let error = chapters(&[0, 30, 600]).validate(&end, &ten).unwrap_err();
+    assert_eq!(error.to_string(), "chapter 3: starts after the end");
+    assert_eq!((error.chapter, error.kind), (3, ChapterErrorKind::AfterEnd));
+}
+
+#[test]
+fn test_chapter_errors() {
+    let error = "0:00 Intro\n1:00 - \n".parse::<Chapters>().unwrap_err();
+    assert_eq!(
+        error,
+        ChapterError {
+            chapter: 2,
+            line: Some(2),
+            kind: ChapterErrorKind::MissingTitle,
+        }
+    );
+    assert_eq!(error.to_string(), "chapter 2 (line 2): missing title");
+}
+
+#[test]
+fn test_parse_chapters_skips_description_lines() {
+    let chapters: Chapters = "10 tips for better audio\n\
+        2024 tour dates below!\n\
+        0:00 Intro\n\
+        \x20 1:05x Not a chapter\n\
+        3:00 Tip 1\n\
+        1.5 million views, thanks\n"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        chapters.chapters,
+        [chapter(0, "Intro"), chapter(180, "Tip 1")]
+    );
+}
+
+#[test]
+fn test_chapters_to_ffmetadata() {
+    let chapters = Chapters {
+        chapters: vec![chapter(0, "Intro"), chapter(125, "Q&A; a=b #1")],
+    };
+    let end = std::time::Duration::from_millis(300_500);
+    assert_eq!(
+        chapters.to_ffmetadata(&end).unwrap(),
+        ";FFMETADATA1
+
+[CHAPTER]
+TIMEBASE=1/1000
+START=0
+END=125000
+title=Intro
+
+[CHAPTER]
+TIMEBASE=1/1000
+START=125000
+END=300500
+title=Q&A\\; a\\=b \\#1
+"
+    );
+    let short = std::time::Duration::from_secs(125);
+    let error = chapters.to_ffmetadata(&short).unwrap_err();
+    assert_eq!((error.chapter, error.kind), (2, ChapterErrorKind::AfterEnd));
+}
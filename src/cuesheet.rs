@@ -0,0 +1,503 @@
+//! CD cue sheets and their "MM:SS:FF" times.
+//!
+//! Cue sheet times count minutes, seconds and CD frames of 1/75 second.
+//! The minutes do not wrap into hours, so 74 minutes are "74:00:00".
+//!
+//! ```
+//! use hhmmss_rs::cuesheet::CueSheet;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let sheet: CueSheet = "FILE \"album.wav\" WAVE\n\
+//!     \x20 TRACK 01 AUDIO\n\
+//!     \x20   INDEX 01 00:00:00\n\
+//!     \x20 TRACK 02 AUDIO\n\
+//!     \x20   INDEX 01 03:25:62\n"
+//!     .parse()
+//!     .unwrap();
+//! let lengths = sheet.files[0].track_lengths();
+//! assert_eq!(lengths[0].unwrap().fmt_cd_frames(), "03:25:62");
+//! assert_eq!(lengths[1], None);
+//! ```
+//!
+//! Times are stored as [`DurationParts`] rounded up to the next nanosecond,
+//! so that they convert back to the same frame.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+/// The number of CD frames per second.
+pub const FRAMES_PER_SECOND: u64 = 75;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Returns the start of the CD frame `frames`, rounded up to the next
+/// nanosecond.
+pub fn frames_to_duration(frames: u64) -> DurationParts {
+    let rest = frames % FRAMES_PER_SECOND;
+    DurationParts::new(
+        false,
+        frames / FRAMES_PER_SECOND,
+        (rest * NANOS_PER_SEC).div_ceil(FRAMES_PER_SECOND) as u32,
+    )
+}
+
+/// Returns the CD frame a duration falls in, ignoring its sign. Saturates
+/// at `u64::MAX`.
+pub fn duration_to_frames<T: Hhmmss + ?Sized>(duration: &T) -> u64 {
+    let parts = duration.to_duration_parts();
+    parts
+        .secs
+        .saturating_mul(FRAMES_PER_SECOND)
+        .saturating_add(parts.nanos as u64 * FRAMES_PER_SECOND / NANOS_PER_SEC)
+}
+
+/// Writes a duration as a cue sheet time, truncated to CD frames.
+pub struct DisplayCdFrames {
+    negative: bool,
+    minutes: u64,
+    seconds: u64,
+    frames: u64,
+}
+
+impl DisplayCdFrames {
+    pub(crate) fn new<T: Hhmmss + ?Sized>(duration: &T) -> Self {
+        Self {
+            negative: duration.is_negative(),
+            minutes: duration.part_of_total_minutes_abs(),
+            seconds: duration.part_of_seconds_abs(),
+            frames: duration.unsigned_subsecs() * FRAMES_PER_SECOND / NANOS_PER_SEC,
+        }
+    }
+}
+
+impl fmt::Display for DisplayCdFrames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative && (self.minutes, self.seconds, self.frames) != (0, 0, 0) {
+            f.write_str("-")?;
+        }
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.minutes, self.seconds, self.frames
+        )
+    }
+}
+
+/// Parses a cue sheet time such as "03:25:62" into [`DurationParts`].
+///
+/// The minutes take two or more digits, the seconds and frames exactly two.
+/// The frames must be below 75. Signs are not accepted.
+pub fn parse_timestamp(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let (minutes_pos, minutes) = cursor.integer(Field::Minutes)?;
+    if cursor.pos - minutes_pos < 2 {
+        return Err(ParseError::InvalidWidth {
+            position: minutes_pos,
+            field: Field::Minutes,
+            width: cursor.pos - minutes_pos,
+        });
+    }
+    cursor.expect(':')?;
+    let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+    cursor.expect(':')?;
+    let (frames_pos, frames) = cursor.two_digits(Field::Frames)?;
+    cursor.finish()?;
+    check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+    if frames >= FRAMES_PER_SECOND {
+        return Err(ParseError::OutOfRange {
+            position: frames_pos,
+            field: Field::Frames,
+            value: frames,
+        });
+    }
+    let frames = minutes
+        .checked_mul(60)
+        .and_then(|s| s.checked_add(seconds))
+        .and_then(|s| s.checked_mul(FRAMES_PER_SECOND))
+        .and_then(|f| f.checked_add(frames))
+        .ok_or(ParseError::Overflow)?;
+    Ok(frames_to_duration(frames))
+}
+
+/// An `INDEX` line of a track.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Index {
+    /// 0 for the pregap, 1 for the start of the track.
+    pub number: u32,
+    /// The position within the file.
+    pub time: DurationParts,
+    /// The file the time refers to, as a position in [`CueSheet::files`],
+    /// if it is not the file of the track. Exact Audio Copy writes the
+    /// indexes of a track that continues in the next file right after that
+    /// `FILE` line.
+    pub file: Option<usize>,
+}
+
+/// A `TRACK` of a cue sheet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Track {
+    pub number: u32,
+    /// The data type, e.g. "AUDIO".
+    pub kind: String,
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub indexes: Vec<Index>,
+    /// All other lines of the track, such as `FLAGS` or `ISRC`, trimmed.
+    /// They are written between the performer and the indexes, except for
+    /// `POSTGAP`, which the format requires after the indexes.
+    pub other: Vec<String>,
+}
+
+impl Track {
+    /// Returns the time of `INDEX 01`, where the track starts. It may refer
+    /// to a later file, see [`Index::file`].
+    pub fn start(&self) -> Option<DurationParts> {
+        self.indexes
+            .iter()
+            .find(|index| index.number == 1)
+            .map(|index| index.time)
+    }
+}
+
+/// A `FILE` of a cue sheet and the tracks it contains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub name: String,
+    /// The file type, e.g. "WAVE" or "MP3".
+    pub kind: String,
+    pub tracks: Vec<Track>,
+    /// The lines between the `FILE` and its first `TRACK` if it has none,
+    /// trimmed. They are written after the `FILE` line.
+    pub other: Vec<String>,
+}
+
+impl File {
+    /// Returns the length of every track, from its start to the start of
+    /// the next track.
+    ///
+    /// The length of the last track depends on the length of the file and
+    /// is `None`, as are the lengths of tracks without an `INDEX 01` in this
+    /// file or followed by one starting earlier.
+    pub fn track_lengths(&self) -> Vec<Option<DurationParts>> {
+        let starts: Vec<_> = self
+            .tracks
+            .iter()
+            .map(|track| {
+                track
+                    .indexes
+                    .iter()
+                    .find(|index| index.number == 1 && index.file.is_none())
+                    .map(|index| duration_to_frames(&index.time))
+            })
+            .collect();
+        (0..starts.len())
+            .map(
+                |i| match (starts[i], starts.get(i + 1).copied().flatten()) {
+                    (Some(start), Some(next)) if next >= start => {
+                        Some(frames_to_duration(next - start))
+                    }
+                    _ => None,
+                },
+            )
+            .collect()
+    }
+}
+
+/// A CD cue sheet.
+///
+/// Parse it with [`str::parse`] and write it back with its [`Display`]
+/// implementation, which writes two spaces of indentation per level, "\n"
+/// line endings and every string quoted.
+///
+/// Only the lines before the first `FILE` belong to the sheet itself. Lines
+/// between a `FILE` and its first `TRACK` are given to that track, or kept
+/// in [`File::other`] if the file has no tracks. `INDEX` lines right after a
+/// `FILE` continue the previous track, as in the multi-file layout of Exact
+/// Audio Copy.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CueSheet {
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub files: Vec<File>,
+    /// All other lines outside of tracks, such as `REM` or `CATALOG`,
+    /// trimmed. They are written first.
+    pub other: Vec<String>,
+}
+
+/// What went wrong in a [`CueSheetError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CueSheetErrorKind {
+    /// A command lacks an argument.
+    MissingArgument,
+    /// A track or index number is not a number.
+    InvalidNumber,
+    /// A quoted string is not closed.
+    UnterminatedQuote,
+    /// An index time could not be parsed. Positions are byte offsets into
+    /// the time.
+    Timestamp(ParseError),
+    /// A `TRACK` comes before the first `FILE`.
+    TrackOutsideFile,
+    /// An `INDEX` comes before the first `TRACK`.
+    IndexOutsideTrack,
+}
+
+/// An error pointing at the offending line of a [`CueSheet`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CueSheetError {
+    /// The line the error was found on, starting at 1.
+    pub line: usize,
+    pub kind: CueSheetErrorKind,
+}
+
+impl fmt::Display for CueSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            CueSheetErrorKind::MissingArgument => f.write_str("missing argument"),
+            CueSheetErrorKind::InvalidNumber => f.write_str("invalid number"),
+            CueSheetErrorKind::UnterminatedQuote => f.write_str("unterminated quote"),
+            CueSheetErrorKind::Timestamp(error) => write!(f, "invalid time: {}", error),
+            CueSheetErrorKind::TrackOutsideFile => f.write_str("TRACK before the first FILE"),
+            CueSheetErrorKind::IndexOutsideTrack => f.write_str("INDEX outside of a TRACK"),
+        }
+    }
+}
+
+impl std::error::Error for CueSheetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            CueSheetErrorKind::Timestamp(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the arguments of a command into words and quoted strings.
+fn arguments(s: &str) -> Result<Vec<&str>, CueSheetErrorKind> {
+    let mut arguments = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let (argument, after) = if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted
+                .find('"')
+                .ok_or(CueSheetErrorKind::UnterminatedQuote)?;
+            (&quoted[..end], &quoted[end + 1..])
+        } else {
+            rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()))
+        };
+        arguments.push(argument);
+        rest = after.trim_start();
+    }
+    Ok(arguments)
+}
+
+/// Checks whether a trimmed line starts with the given command.
+fn is_command(line: &str, command: &str) -> bool {
+    line.split_whitespace()
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case(command))
+}
+
+impl FromStr for CueSheet {
+    type Err = CueSheetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+        let mut sheet = CueSheet::default();
+        // Lines between a FILE and its first TRACK, kept for that track.
+        let mut pending = Track {
+            number: 0,
+            kind: String::new(),
+            title: None,
+            performer: None,
+            indexes: Vec::new(),
+            other: Vec::new(),
+        };
+        // Gives the pending lines to the last file if no track took them.
+        let flush = |sheet: &mut CueSheet, pending: &mut Track| {
+            if let Some(file) = sheet.files.last_mut() {
+                let title = pending.title.take().map(|t| format!("TITLE \"{}\"", t));
+                let performer = pending
+                    .performer
+                    .take()
+                    .map(|p| format!("PERFORMER \"{}\"", p));
+                file.other.extend(title.into_iter().chain(performer));
+                file.other.append(&mut pending.other);
+            }
+        };
+        for (i, line) in s.lines().enumerate() {
+            let error = |kind| CueSheetError { line: i + 1, kind };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (command, rest) =
+                line.split_at(line.find(char::is_whitespace).unwrap_or(line.len()));
+            let args = arguments(rest).map_err(error)?;
+            let arg = |n: usize| {
+                args.get(n)
+                    .map(|arg| (*arg).to_owned())
+                    .ok_or(error(CueSheetErrorKind::MissingArgument))
+            };
+            let number = |n: usize| {
+                arg(n)?
+                    .parse::<u32>()
+                    .map_err(|_| error(CueSheetErrorKind::InvalidNumber))
+            };
+            let in_file = !sheet.files.is_empty();
+            let in_track = sheet
+                .files
+                .last()
+                .is_some_and(|file| !file.tracks.is_empty());
+            let file_count = sheet.files.len();
+            // Sheet-level lines come before the first FILE.
+            let context = match sheet
+                .files
+                .last_mut()
+                .and_then(|file| file.tracks.last_mut())
+            {
+                Some(track) => Some(track),
+                None if in_file => Some(&mut pending),
+                None => None,
+            };
+            match command.to_ascii_uppercase().as_str() {
+                "FILE" => {
+                    let file = File {
+                        name: arg(0)?,
+                        kind: arg(1)?,
+                        tracks: Vec::new(),
+                        other: Vec::new(),
+                    };
+                    flush(&mut sheet, &mut pending);
+                    sheet.files.push(file);
+                }
+                "TRACK" => {
+                    let track = Track {
+                        number: number(0)?,
+                        kind: arg(1)?,
+                        title: pending.title.take(),
+                        performer: pending.performer.take(),
+                        indexes: Vec::new(),
+                        other: std::mem::take(&mut pending.other),
+                    };
+                    sheet
+                        .files
+                        .last_mut()
+                        .ok_or(error(CueSheetErrorKind::TrackOutsideFile))?
+                        .tracks
+                        .push(track);
+                }
+                "INDEX" => {
+                    let number = number(0)?;
+                    let time = parse_timestamp(&arg(1)?)
+                        .map_err(|e| error(CueSheetErrorKind::Timestamp(e)))?;
+                    let (track, file) = match context {
+                        Some(track) if in_track => (track, None),
+                        // Continues the last track of an earlier file.
+                        _ => (
+                            sheet
+                                .files
+                                .iter_mut()
+                                .rev()
+                                .find_map(|file| file.tracks.last_mut())
+                                .ok_or(error(CueSheetErrorKind::IndexOutsideTrack))?,
+                            Some(file_count - 1),
+                        ),
+                    };
+                    track.indexes.push(Index { number, time, file });
+                }
+                "TITLE" => match context {
+                    Some(track) => track.title = Some(arg(0)?),
+                    None => sheet.title = Some(arg(0)?),
+                },
+                "PERFORMER" => match context {
+                    Some(track) => track.performer = Some(arg(0)?),
+                    None => sheet.performer = Some(arg(0)?),
+                },
+                _ => match context {
+                    Some(track) => track.other.push(line.to_owned()),
+                    None => sheet.other.push(line.to_owned()),
+                },
+            }
+        }
+        flush(&mut sheet, &mut pending);
+        Ok(sheet)
+    }
+}
+
+fn write_index(f: &mut fmt::Formatter<'_>, index: &Index) -> fmt::Result {
+    writeln!(
+        f,
+        "    INDEX {:02} {}",
+        index.number,
+        index.time.display_cd_frames()
+    )
+}
+
+impl fmt::Display for CueSheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.other {
+            writeln!(f, "{}", line)?;
+        }
+        if let Some(performer) = &self.performer {
+            writeln!(f, "PERFORMER \"{}\"", performer)?;
+        }
+        if let Some(title) = &self.title {
+            writeln!(f, "TITLE \"{}\"", title)?;
+        }
+        for (i, file) in self.files.iter().enumerate() {
+            writeln!(f, "FILE \"{}\" {}", file.name, file.kind)?;
+            for index in self.files[..i]
+                .iter()
+                .flat_map(|file| &file.tracks)
+                .flat_map(|track| &track.indexes)
+                .filter(|index| index.file == Some(i))
+            {
+                write_index(f, index)?;
+            }
+            for line in &file.other {
+                writeln!(f, "{}", line)?;
+            }
+            for track in &file.tracks {
+                writeln!(f, "  TRACK {:02} {}", track.number, track.kind)?;
+                if let Some(title) = &track.title {
+                    writeln!(f, "    TITLE \"{}\"", title)?;
+                }
+                if let Some(performer) = &track.performer {
+                    writeln!(f, "    PERFORMER \"{}\"", performer)?;
+                }
+                let (postgap, other): (Vec<_>, Vec<_>) = track
+                    .other
+                    .iter()
+                    .partition(|line| is_command(line, "POSTGAP"));
+                for line in other {
+                    writeln!(f, "    {}", line)?;
+                }
+                for index in track.indexes.iter().filter(|index| index.file.is_none()) {
+                    write_index(f, index)?;
+                }
+                for line in postgap {
+                    writeln!(f, "    {}", line)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CueSheet {
+    /// Returns the tracks of all files in order.
+    pub fn tracks(&self) -> impl Iterator<Item = &Track> {
+        self.files.iter().flat_map(|file| &file.tracks)
+    }
+}
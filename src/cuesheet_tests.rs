@@ -0,0 +1,304 @@
+use crate::cuesheet::{
+    duration_to_frames, frames_to_duration, parse_timestamp, CueSheet, CueSheetError,
+    CueSheetErrorKind, Index,
+};
+use crate::parse::{DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+const SAMPLE: &str = "\u{feff}REM GENRE Rock\r
+REM DATE 1999\r
+PERFORMER \"The Band\"\r
+TITLE \"Live Album\"\r
+FILE \"side a.wav\" WAVE\r
+  TRACK 01 AUDIO\r
+    TITLE \"Intro\"\r
+    PERFORMER \"The Band\"\r
+    INDEX 01 00:00:00\r
+  TRACK 02 AUDIO\r
+    TITLE \"Second Song\"\r
+    FLAGS DCP\r
+    INDEX 00 03:23:00\r
+    INDEX 01 03:25:62\r
+  TRACK 03 AUDIO\r
+    INDEX 01 74:59:74\r
+FILE side_b.mp3 MP3\r
+  TRACK 04 AUDIO\r
+    INDEX 01 00:00:00\r
+";
+
+#[test]
+fn test_fmt_cd_frames() {
+    let d = std::time::Duration::new(5025, 666_666_666);
+    assert_eq!(d.fmt_cd_frames(), "83:45:49");
+    assert_eq!(
+        std::time::Duration::new(5025, 666_666_667).fmt_cd_frames(),
+        "83:45:50"
+    );
+    assert_eq!(std::time::Duration::ZERO.fmt_cd_frames(), "00:00:00");
+    assert_eq!(
+        DurationParts::new(true, 1, 13_333_334).fmt_cd_frames(),
+        "-00:01:01"
+    );
+    assert_eq!(
+        DurationParts::new(true, 0, 13_333_333).fmt_cd_frames(),
+        "00:00:00"
+    );
+}
+
+#[test]
+fn test_cd_frames_round_trip() {
+    for frames in [0, 1, 2, 74, 75, 15_412, 337_499] {
+        let d = frames_to_duration(frames);
+        assert_eq!(duration_to_frames(&d), frames);
+        assert_eq!(parse_timestamp(&d.fmt_cd_frames()), Ok(d));
+    }
+    assert_eq!(
+        frames_to_duration(1),
+        DurationParts::new(false, 0, 13_333_334)
+    );
+    assert_eq!(
+        duration_to_frames(&std::time::Duration::new(u64::MAX, 999_999_999)),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_parse_cd_timestamp() {
+    assert_eq!(
+        parse_timestamp("03:25:62").map(|d| duration_to_frames(&d)),
+        Ok((3 * 60 + 25) * 75 + 62)
+    );
+    assert_eq!(
+        parse_timestamp("100:00:00"),
+        Ok(DurationParts::new(false, 6000, 0))
+    );
+    assert_eq!(
+        parse_timestamp("00:00:75"),
+        Err(ParseError::OutOfRange {
+            position: 6,
+            field: Field::Frames,
+            value: 75,
+        })
+    );
+    assert_eq!(
+        parse_timestamp("00:60:00"),
+        Err(ParseError::OutOfRange {
+            position: 3,
+            field: Field::Seconds,
+            value: 60,
+        })
+    );
+    assert_eq!(
+        parse_timestamp("3:25:62"),
+        Err(ParseError::InvalidWidth {
+            position: 0,
+            field: Field::Minutes,
+            width: 1,
+        })
+    );
+    assert_eq!(parse_timestamp(""), Err(ParseError::Empty));
+    assert_eq!(
+        parse_timestamp("4099276460824344:48:74"),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+fn test_parse_cue_sheet() {
+    let sheet: CueSheet = SAMPLE.parse().unwrap();
+    assert_eq!(sheet.title.as_deref(), Some("Live Album"));
+    assert_eq!(sheet.performer.as_deref(), Some("The Band"));
+    assert_eq!(sheet.other, ["REM GENRE Rock", "REM DATE 1999"]);
+    assert_eq!(sheet.files.len(), 2);
+    assert_eq!(sheet.files[0].name, "side a.wav");
+    assert_eq!(sheet.files[1].name, "side_b.mp3");
+    assert_eq!(sheet.files[1].kind, "MP3");
+
+    let tracks: Vec<_> = sheet.tracks().collect();
+    assert_eq!(tracks.len(), 4);
+    assert_eq!(tracks[1].number, 2);
+    assert_eq!(tracks[1].title.as_deref(), Some("Second Song"));
+    assert_eq!(tracks[1].performer, None);
+    assert_eq!(tracks[1].other, ["FLAGS DCP"]);
+    assert_eq!(tracks[1].indexes.len(), 2);
+    assert_eq!(tracks[1].start().unwrap().fmt_cd_frames(), "03:25:62");
+    assert_eq!(tracks[2].start().unwrap().hhmmssxxx(), "01:14:59.986");
+
+    let lengths = sheet.files[0].track_lengths();
+    assert_eq!(lengths.len(), 3);
+    assert_eq!(lengths[0].unwrap().fmt_cd_frames(), "03:25:62");
+    assert_eq!(lengths[1].unwrap().fmt_cd_frames(), "71:34:12");
+    assert_eq!(lengths[2], None);
+    assert_eq!(sheet.files[1].track_lengths(), [None]);
+}
+
+#[test]
+fn test_write_cue_sheet() {
+    let sheet: CueSheet = SAMPLE.parse().unwrap();
+    let written = sheet.to_string();
+    assert_eq!(
+        written,
+        "REM GENRE Rock
+REM DATE 1999
+PERFORMER \"The Band\"
+TITLE \"Live Album\"
+FILE \"side a.wav\" WAVE
+  TRACK 01 AUDIO
+    TITLE \"Intro\"
+    PERFORMER \"The Band\"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE \"Second Song\"
+    FLAGS DCP
+    INDEX 00 03:23:00
+    INDEX 01 03:25:62
+  TRACK 03 AUDIO
+    INDEX 01 74:59:74
+FILE \"side_b.mp3\" MP3
+  TRACK 04 AUDIO
+    INDEX 01 00:00:00
+"
+    );
+    assert_eq!(written.parse::<CueSheet>(), Ok(sheet));
+}
+
+#[test]
+fn test_write_cue_sheet_postgap() {
+    let input = "FILE \"a.wav\" WAVE
+  TRACK 01 AUDIO
+    PREGAP 00:02:00
+    ISRC ABCDE1234567
+    INDEX 01 00:00:00
+    POSTGAP 00:02:00
+  TRACK 02 AUDIO
+    INDEX 01 03:00:00
+";
+    let sheet: CueSheet = input.parse().unwrap();
+    assert_eq!(
+        sheet.files[0].tracks[0].other,
+        ["PREGAP 00:02:00", "ISRC ABCDE1234567", "POSTGAP 00:02:00"]
+    );
+    let written = sheet.to_string();
+    assert_eq!(written, input);
+    assert_eq!(written.parse::<CueSheet>(), Ok(sheet));
+}
+
+#[test]
+fn test_parse_cue_sheet_lines_before_first_track() {
+    let sheet: CueSheet = "TITLE \"Album\"
+FILE a.wav WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+FILE b.wav WAVE
+TITLE \"Second Side\"
+PERFORMER \"Guest\"
+REM COMMENT side b
+  TRACK 02 AUDIO
+    INDEX 01 00:00:00
+"
+    .parse()
+    .unwrap();
+    assert_eq!(sheet.title.as_deref(), Some("Album"));
+    assert_eq!(sheet.performer, None);
+    assert!(sheet.other.is_empty());
+    let track = &sheet.files[1].tracks[0];
+    assert_eq!(track.title.as_deref(), Some("Second Side"));
+    assert_eq!(track.performer.as_deref(), Some("Guest"));
+    assert_eq!(track.other, ["REM COMMENT side b"]);
+    assert_eq!(sheet.files[0].tracks[0].title, None);
+}
+
+#[test]
+fn test_parse_cue_sheet_multiple_files() {
+    // Exact Audio Copy's layout with the gaps appended to the previous file.
+    let input = "PERFORMER \"Artist\"
+TITLE \"Album\"
+FILE \"01 - One.wav\" WAVE
+  TRACK 01 AUDIO
+    TITLE \"One\"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE \"Two\"
+    INDEX 00 04:10:20
+FILE \"02 - Two.wav\" WAVE
+    INDEX 01 00:00:00
+  TRACK 03 AUDIO
+    TITLE \"Three\"
+    INDEX 00 03:58:40
+FILE \"03 - Three.wav\" WAVE
+    INDEX 01 00:00:00
+REM COMMENT last file
+";
+    let sheet: CueSheet = input.parse().unwrap();
+    assert_eq!(sheet.files.len(), 3);
+    assert_eq!(sheet.tracks().count(), 3);
+    let two = &sheet.files[0].tracks[1];
+    assert_eq!(
+        two.indexes,
+        [
+            Index {
+                number: 0,
+                time: DurationParts::new(false, 250, 266_666_667),
+                file: None,
+            },
+            Index {
+                number: 1,
+                time: DurationParts::new(false, 0, 0),
+                file: Some(1),
+            },
+        ]
+    );
+    assert_eq!(sheet.files[1].tracks[0].title.as_deref(), Some("Three"));
+    assert_eq!(sheet.files[1].tracks[0].indexes[1].file, Some(2));
+    assert!(sheet.files[2].tracks.is_empty());
+    assert_eq!(sheet.files[2].other, ["REM COMMENT last file"]);
+    assert_eq!(sheet.files[0].track_lengths(), [None, None]);
+    let written = sheet.to_string();
+    assert_eq!(written, input);
+    assert_eq!(written.parse::<CueSheet>(), Ok(sheet));
+}
+
+#[test]
+fn test_cue_sheet_errors() {
+    let error = |s: &str| s.parse::<CueSheet>().unwrap_err();
+    assert_eq!(
+        error("TRACK 01 AUDIO\n"),
+        CueSheetError {
+            line: 1,
+            kind: CueSheetErrorKind::TrackOutsideFile,
+        }
+    );
+    assert_eq!(
+        error("FILE a.wav WAVE\nINDEX 01 00:00:00\n").kind,
+        CueSheetErrorKind::IndexOutsideTrack
+    );
+    assert_eq!(
+        error("FILE \"a.wav WAVE\n"),
+        CueSheetError {
+            line: 1,
+            kind: CueSheetErrorKind::UnterminatedQuote,
+        }
+    );
+    assert_eq!(
+        error("FILE a.wav\n").kind,
+        CueSheetErrorKind::MissingArgument
+    );
+    assert_eq!(
+        error("FILE a.wav WAVE\n  TRACK one AUDIO\n").kind,
+        CueSheetErrorKind::InvalidNumber
+    );
+    let e = error("FILE a.wav WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:80\n");
+    assert_eq!(e.line, 3);
+    assert_eq!(
+        e.kind,
+        CueSheetErrorKind::Timestamp(ParseError::OutOfRange {
+            position: 6,
+            field: Field::Frames,
+            value: 80,
+        })
+    );
+    assert_eq!(
+        e.to_string(),
+        "line 3: invalid time: frames at 6 is out of range: 80"
+    );
+}
@@ -0,0 +1,62 @@
+use crate::{FractPartOfDuration, Hhmmss};
+
+const ONE_DAY_TWO_HOURS: u64 = ((24 + 2) * 60 + 3) * 60 + 4;
+
+#[test]
+fn test_days_std() {
+    let d = std::time::Duration::new(3 * 24 * 3600, 0);
+    assert_eq!(d.part_of_days(), 3);
+    assert_eq!(d.part_of_days_abs(), 3);
+    assert_eq!(d.part_of_hours_of_day_abs(), 0);
+    assert_eq!(d.hhmmss(), "72:00:00");
+    assert_eq!(d.dhhmmss(), "3d 00:00:00");
+
+    let d = std::time::Duration::new(ONE_DAY_TWO_HOURS, 500_000_000);
+    assert_eq!(d.part_of_hours_of_day(), 2);
+    assert_eq!(d.dhhmmss(), "1d 02:03:04");
+    assert_eq!(d.dhhmmssxxx(), "1d 02:03:04.500");
+    assert_eq!(
+        d.dhhmmss_and_fract(FractPartOfDuration::Microseconds),
+        "1d 02:03:04.500000"
+    );
+    assert_eq!(d.fmt_timespan(), "1.02:03:04.5000000");
+    assert_eq!(d.fmt_timedelta(), "1 day, 2:03:04.500000");
+}
+
+#[test]
+fn test_days_under_one_day() {
+    let d = std::time::Duration::new(2 * 3600 + 3 * 60 + 4, 0);
+    assert_eq!(d.dhhmmss(), "02:03:04");
+    assert_eq!(d.fmt_timespan(), "02:03:04");
+    assert_eq!(d.fmt_timedelta(), "2:03:04");
+
+    let d = std::time::Duration::new(2 * 24 * 3600 + 4, 1_000);
+    assert_eq!(d.fmt_timespan(), "2.00:00:04.0000010");
+    assert_eq!(d.fmt_timedelta(), "2 days, 0:00:04.000001");
+    let d = std::time::Duration::new(4, 99);
+    assert_eq!(d.fmt_timespan(), "00:00:04");
+    assert_eq!(d.fmt_timedelta(), "0:00:04");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_days_chrono() {
+    let d = -chrono::Duration::seconds(ONE_DAY_TWO_HOURS as i64);
+    assert_eq!(d.part_of_days(), -1);
+    assert_eq!(d.part_of_days_abs(), 1);
+    assert_eq!(d.part_of_hours_of_day(), -2);
+    assert_eq!(d.dhhmmss(), "-1d 02:03:04");
+    assert_eq!(d.display_dhhmmss().unsigned().to_string(), "1d 02:03:04");
+    assert_eq!(d.fmt_timespan(), "-1.02:03:04");
+    assert_eq!(d.fmt_timedelta(), "-1 day, 2:03:04");
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_days_time() {
+    let d = time::Duration::new(-(ONE_DAY_TWO_HOURS as i64), -123_000_000);
+    assert_eq!(d.part_of_days(), -1);
+    assert_eq!(d.part_of_days_abs(), 1);
+    assert_eq!(d.dhhmmssxxx(), "-1d 02:03:04.123");
+    assert_eq!(d.fmt_timespan(), "-1.02:03:04.1230000");
+}
@@ -0,0 +1,350 @@
+//! Allocation-free [`Display`](fmt::Display) adapters for [`Hhmmss`] types.
+//!
+//! The adapters borrow the duration and write straight into the formatter,
+//! so they can be used with `write!` without any intermediate `String`. The
+//! `String`-returning methods of [`Hhmmss`] are implemented on top of them.
+
+use std::fmt::{self, Write as _};
+
+use crate::fracts::{FractPartOfDuration, Precision};
+use crate::Hhmmss;
+
+/// Writes "-" for negative durations and nothing otherwise.
+pub struct Sign<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Sign<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.duration.is_negative() {
+            f.write_char('-')
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Writes the leading digits of the fractional seconds, without a dot.
+pub struct Fract<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    pub(crate) included: Precision,
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Fract<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_fract(
+            f,
+            self.duration.unsigned_subsecs(),
+            self.included.decimal_places(),
+        )
+    }
+}
+
+/// Writes the leading `places` digits of `nanos`, zero-padded.
+pub(crate) fn write_fract(f: &mut fmt::Formatter<'_>, nanos: u64, places: usize) -> fmt::Result {
+    let value = nanos / 10u64.pow(9 - places as u32);
+    write!(f, "{:0width$}", value, width = places)
+}
+
+/// Writes `nanos` as fractional digits without trailing zeros. Nothing is
+/// written for zero.
+pub(crate) fn write_fract_trimmed(f: &mut fmt::Formatter<'_>, nanos: u64) -> fmt::Result {
+    if nanos == 0 {
+        return Ok(());
+    }
+    let mut value = nanos;
+    let mut places = 9;
+    while value.is_multiple_of(10) {
+        value /= 10;
+        places -= 1;
+    }
+    write!(f, "{:0width$}", value, width = places)
+}
+
+/// A single zero-padded component of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+}
+
+/// Writes one component such as "HH" or "-xxx".
+pub struct Component<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    pub(crate) unit: Unit,
+    pub(crate) signed: bool,
+}
+
+impl<T: ?Sized> Component<'_, T> {
+    /// Omits the sign.
+    pub fn unsigned(mut self) -> Self {
+        self.signed = false;
+        self
+    }
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Component<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        if self.signed {
+            d.display_sign().fmt(f)?;
+        }
+        match self.unit {
+            Unit::Hours => write!(f, "{:02}", d.part_of_hours_abs()),
+            Unit::Minutes => write!(f, "{:02}", d.part_of_minutes_abs()),
+            Unit::Seconds => write!(f, "{:02}", d.part_of_seconds_abs()),
+            Unit::Milliseconds => write!(f, "{:03}", d.part_of_milliseconds_abs()),
+        }
+    }
+}
+
+/// The largest unit written by a [`Clock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Leading {
+    Hours,
+    /// Minutes of the hour, dropping whole hours.
+    Minutes,
+    /// Minutes including whole hours, which do not wrap at 60.
+    TotalMinutes,
+}
+
+/// An error returned by the checked layouts when the duration is too long
+/// for the layout and would otherwise be truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TruncationError {
+    /// The whole hours the layout cannot represent.
+    pub hours: u64,
+}
+
+impl fmt::Display for TruncationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "duration of {} hours or more does not fit into a minutes-and-seconds layout",
+            self.hours
+        )
+    }
+}
+
+impl std::error::Error for TruncationError {}
+
+/// Writes a colon-separated layout such as "HH:MM:SS" or "-M:SS.xxx".
+pub struct Clock<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    pub(crate) leading: Leading,
+    pub(crate) padded: bool,
+    pub(crate) fract: Option<Precision>,
+    pub(crate) decimal_separator: char,
+    pub(crate) signed: bool,
+}
+
+impl<'a, T: ?Sized> Clock<'a, T> {
+    pub(crate) fn new(duration: &'a T, leading: Leading, padded: bool) -> Self {
+        Self {
+            duration,
+            leading,
+            padded,
+            fract: None,
+            decimal_separator: '.',
+            signed: true,
+        }
+    }
+
+    pub(crate) fn with_fract(mut self, included: Precision) -> Self {
+        self.fract = Some(included);
+        self
+    }
+
+    pub(crate) fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Omits the sign.
+    pub fn unsigned(mut self) -> Self {
+        self.signed = false;
+        self
+    }
+}
+
+impl<T: Hhmmss + ?Sized> Clock<'_, T> {
+    /// Fails instead of silently dropping whole hours from a layout that
+    /// starts with the minutes of the hour, such as "MM:SS".
+    pub fn checked(self) -> Result<Self, TruncationError> {
+        let hours = self.duration.part_of_hours_abs();
+        if self.leading == Leading::Minutes && hours != 0 {
+            Err(TruncationError { hours })
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Clock<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        if self.signed {
+            d.display_sign().fmt(f)?;
+        }
+        let leading = match self.leading {
+            Leading::Hours => d.part_of_hours_abs(),
+            Leading::Minutes => d.part_of_minutes_abs(),
+            Leading::TotalMinutes => d.part_of_total_minutes_abs(),
+        };
+        if self.padded {
+            write!(f, "{:02}", leading)?;
+        } else {
+            write!(f, "{}", leading)?;
+        }
+        if self.leading == Leading::Hours {
+            write!(f, ":{:02}", d.part_of_minutes_abs())?;
+        }
+        write!(f, ":{:02}", d.part_of_seconds_abs())?;
+        if let Some(included) = self.fract {
+            f.write_char(self.decimal_separator)?;
+            d.display_fract(included).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// How a [`DayClock`] writes the days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DayStyle {
+    /// "1d 02:03:04"
+    Suffix,
+    /// "1.02:03:04.5000000", as .NET's `TimeSpan`.
+    TimeSpan,
+    /// "1 day, 2:03:04.500000", as Python's `timedelta`.
+    TimeDelta,
+}
+
+/// Writes a layout in which whole days are split off the hours, such as
+/// "1d 02:03:04", "1.02:03:04" or "1 day, 2:03:04".
+pub struct DayClock<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    pub(crate) style: DayStyle,
+    pub(crate) fract: Option<Precision>,
+    pub(crate) signed: bool,
+}
+
+impl<'a, T: ?Sized> DayClock<'a, T> {
+    pub(crate) fn new(duration: &'a T, style: DayStyle) -> Self {
+        Self {
+            duration,
+            style,
+            fract: None,
+            signed: true,
+        }
+    }
+
+    pub(crate) fn with_fract(mut self, included: Precision) -> Self {
+        self.fract = Some(included);
+        self
+    }
+
+    /// Omits the sign.
+    pub fn unsigned(mut self) -> Self {
+        self.signed = false;
+        self
+    }
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for DayClock<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        if self.signed {
+            d.display_sign().fmt(f)?;
+        }
+        let days = d.part_of_days_abs();
+        let hours = d.part_of_hours_of_day_abs();
+        let subsecs = d.unsigned_subsecs();
+        match self.style {
+            DayStyle::Suffix => {
+                if days != 0 {
+                    write!(f, "{}d ", days)?;
+                }
+                write!(f, "{:02}", hours)?;
+            }
+            DayStyle::TimeSpan => {
+                if days != 0 {
+                    write!(f, "{}.", days)?;
+                }
+                write!(f, "{:02}", hours)?;
+            }
+            DayStyle::TimeDelta => {
+                match days {
+                    0 => {}
+                    1 => f.write_str("1 day, ")?,
+                    _ => write!(f, "{} days, ", days)?,
+                }
+                write!(f, "{}", hours)?;
+            }
+        }
+        write!(
+            f,
+            ":{:02}:{:02}",
+            d.part_of_minutes_abs(),
+            d.part_of_seconds_abs()
+        )?;
+        match self.style {
+            DayStyle::Suffix => {
+                if let Some(included) = self.fract {
+                    f.write_char('.')?;
+                    d.display_fract(included).fmt(f)?;
+                }
+            }
+            DayStyle::TimeSpan if subsecs >= 100 => {
+                f.write_char('.')?;
+                write_fract(f, subsecs, 7)?;
+            }
+            DayStyle::TimeDelta if subsecs >= 1_000 => {
+                f.write_char('.')?;
+                write_fract(f, subsecs, 6)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Writes the output of [`Hhmmss::fmt_smart`].
+pub struct Smart<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Smart<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        if d.part_of_microseconds() != 0 || d.part_of_nanoseconds() != 0 {
+            f.write_str("about ")?;
+        }
+        if d.part_of_milliseconds() == 0 {
+            if d.part_of_hours() != 0 {
+                d.display_hmmss().fmt(f)
+            } else if d.part_of_minutes() != 0 {
+                d.display_mss().fmt(f)
+            } else if d.part_of_seconds() != 0 {
+                write!(f, "{}s", d.part_of_seconds())
+            } else {
+                f.write_char('0')
+            }
+        } else if d.part_of_hours() != 0 {
+            d.display_hmmss_and_fract(FractPartOfDuration::Milliseconds)
+                .fmt(f)
+        } else if d.part_of_minutes() != 0 {
+            d.display_mss_and_fract(FractPartOfDuration::Milliseconds)
+                .fmt(f)
+        } else {
+            write!(
+                f,
+                "{}{}.{}s",
+                d.display_sign(),
+                d.part_of_seconds_abs(),
+                d.display_fract(FractPartOfDuration::Milliseconds)
+            )
+        }
+    }
+}
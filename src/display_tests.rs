@@ -0,0 +1,63 @@
+#![allow(clippy::identity_op)]
+
+use std::fmt::Write as _;
+
+use crate::{FractPartOfDuration, Hhmmss};
+
+#[test]
+fn test_display_adapters_std() {
+    let d = std::time::Duration::new((1 * 60 + 23) * 60 + 45, 678_901_234);
+    let mut buf = String::new();
+    write!(buf, "[{}]", d.display_hhmmssxxx()).unwrap();
+    assert_eq!(buf, "[01:23:45.678]");
+    assert_eq!(d.display_hmmss().to_string(), "1:23:45");
+    assert_eq!(d.display_mss().to_string(), "23:45");
+    assert_eq!(
+        d.display_mmss_and_fract(FractPartOfDuration::Microseconds)
+            .to_string(),
+        "23:45.678901"
+    );
+    assert_eq!(
+        d.display_fract(FractPartOfDuration::Nanoseconds)
+            .to_string(),
+        "678901234"
+    );
+    assert_eq!(d.display_sign().to_string(), "");
+    assert_eq!(d.display_xxx().to_string(), "678");
+}
+
+#[test]
+fn test_display_fract_padding() {
+    let d = std::time::Duration::new(1, 1_002_003);
+    assert_eq!(
+        d.display_fract(FractPartOfDuration::Milliseconds)
+            .to_string(),
+        "001"
+    );
+    assert_eq!(
+        d.display_fract(FractPartOfDuration::Microseconds)
+            .to_string(),
+        "001002"
+    );
+    assert_eq!(d.fmt_fract_all(), "001002003");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_display_adapters_chrono() {
+    let d = chrono::Duration::seconds(-((1 * 60 + 23) * 60 + 45))
+        + chrono::Duration::milliseconds(-678);
+    assert_eq!(d.display_hhmmssxxx().to_string(), "-01:23:45.678");
+    assert_eq!(d.display_hhmmssxxx().unsigned().to_string(), "01:23:45.678");
+    assert_eq!(d.display_hh().to_string(), "-01");
+    assert_eq!(d.display_hh().unsigned().to_string(), "01");
+    assert_eq!(d.display_smart().to_string(), "-1:23:45.678");
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_fmt_smart_negative_subsecond() {
+    // The sign comes from the whole seconds, as in the other layouts.
+    let d = time::Duration::milliseconds(-500);
+    assert_eq!(d.fmt_smart(), "0.500s");
+}
@@ -0,0 +1,233 @@
+//! Pattern-based duration formatting.
+//!
+//! A pattern is compiled once into a [`DurationFormat`] and can then be
+//! applied to any [`Hhmmss`] type. The following specifiers are recognized:
+//!
+//! | Specifier | Output                                              |
+//! |-----------|-----------------------------------------------------|
+//! | `%H`      | hours, at least two digits ("01", "123")            |
+//! | `%-H`     | hours without padding ("1")                         |
+//! | `%M`      | minutes of the hour, two digits ("05")              |
+//! | `%-M`     | minutes of the hour without padding ("5")           |
+//! | `%m`      | total minutes, not wrapping at 60 ("83")            |
+//! | `%-m`     | total minutes without padding ("5")                 |
+//! | `%S`      | seconds of the minute, two digits ("07")            |
+//! | `%-S`     | seconds of the minute without padding ("7")         |
+//! | `%f`      | all nine fractional digits ("678901234")            |
+//! | `%1f`–`%9f` | the leading 1 to 9 fractional digits ("678")      |
+//! | `%s`      | "-" for negative durations, nothing otherwise       |
+//! | `%+`      | "-" for negative durations, "+" otherwise           |
+//! | `%%`      | a literal "%"                                       |
+//!
+//! Any other text is copied verbatim.
+//!
+//! ```
+//! use hhmmss_rs::format::DurationFormat;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let format = DurationFormat::parse("%s%H:%M:%S.%3f").unwrap();
+//! let d = std::time::Duration::new(5025, 678_901_234);
+//! assert_eq!(d.fmt_with(&format), "01:23:45.678");
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::display::write_fract;
+use crate::Hhmmss;
+
+/// An error returned when a format pattern cannot be compiled.
+///
+/// Every `position` is a byte offset into the pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatError {
+    /// The pattern ended right after a "%", "%-" or precision digit.
+    UnexpectedEnd { position: usize },
+    /// The character after "%" is not a known specifier.
+    UnknownSpecifier { position: usize, found: char },
+    /// "%-" was applied to a specifier that has no padding.
+    UnpaddableSpecifier { position: usize, found: char },
+    /// A fractional precision outside 1 to 9 was requested.
+    InvalidPrecision { position: usize, precision: u32 },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::UnexpectedEnd { position } => {
+                write!(f, "incomplete specifier at {}", position)
+            }
+            FormatError::UnknownSpecifier { position, found } => {
+                write!(f, "unknown specifier {:?} at {}", found, position)
+            }
+            FormatError::UnpaddableSpecifier { position, found } => {
+                write!(
+                    f,
+                    "specifier {:?} at {} cannot be unpadded",
+                    found, position
+                )
+            }
+            FormatError::InvalidPrecision {
+                position,
+                precision,
+            } => write!(f, "invalid precision {} at {}", precision, position),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Literal(String),
+    Hours { padded: bool },
+    Minutes { padded: bool },
+    TotalMinutes { padded: bool },
+    Seconds { padded: bool },
+    Fract { places: usize },
+    Sign { explicit: bool },
+}
+
+/// A compiled duration format pattern.
+///
+/// See the [module documentation](self) for the pattern syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationFormat {
+    items: Vec<Item>,
+}
+
+impl DurationFormat {
+    /// Compiles a pattern.
+    pub fn parse(pattern: &str) -> Result<Self, FormatError> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.char_indices();
+        while let Some((_, c)) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            let (mut position, mut spec) = chars.next().ok_or(FormatError::UnexpectedEnd {
+                position: pattern.len(),
+            })?;
+            if spec == '%' {
+                literal.push('%');
+                continue;
+            }
+            let mut padded = true;
+            if spec == '-' {
+                padded = false;
+                (position, spec) = chars.next().ok_or(FormatError::UnexpectedEnd {
+                    position: pattern.len(),
+                })?;
+            }
+            let item = match spec {
+                'H' => Item::Hours { padded },
+                'M' => Item::Minutes { padded },
+                'm' => Item::TotalMinutes { padded },
+                'S' => Item::Seconds { padded },
+                'f' if padded => Item::Fract { places: 9 },
+                's' if padded => Item::Sign { explicit: false },
+                '+' if padded => Item::Sign { explicit: true },
+                '0'..='9' if padded => {
+                    let precision = spec.to_digit(10).unwrap();
+                    match chars.next() {
+                        Some((_, 'f')) if precision >= 1 => Item::Fract {
+                            places: precision as usize,
+                        },
+                        Some((_, 'f')) => {
+                            return Err(FormatError::InvalidPrecision {
+                                position,
+                                precision,
+                            })
+                        }
+                        Some((position, found)) => {
+                            return Err(FormatError::UnknownSpecifier { position, found })
+                        }
+                        None => {
+                            return Err(FormatError::UnexpectedEnd {
+                                position: pattern.len(),
+                            })
+                        }
+                    }
+                }
+                'f' | 's' | '+' | '0'..='9' => {
+                    return Err(FormatError::UnpaddableSpecifier {
+                        position,
+                        found: spec,
+                    })
+                }
+                found => return Err(FormatError::UnknownSpecifier { position, found }),
+            };
+            if !literal.is_empty() {
+                items.push(Item::Literal(std::mem::take(&mut literal)));
+            }
+            items.push(item);
+        }
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+        Ok(Self { items })
+    }
+
+    /// Returns an adapter writing `duration` in this format.
+    pub fn display<'a, T: Hhmmss + ?Sized>(&'a self, duration: &'a T) -> Formatted<'a, T> {
+        Formatted {
+            format: self,
+            duration,
+        }
+    }
+
+    /// Formats `duration` into a new `String`.
+    pub fn format<T: Hhmmss + ?Sized>(&self, duration: &T) -> String {
+        self.display(duration).to_string()
+    }
+}
+
+impl std::str::FromStr for DurationFormat {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Writes a duration using a [`DurationFormat`].
+pub struct Formatted<'a, T: ?Sized> {
+    format: &'a DurationFormat,
+    duration: &'a T,
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Formatted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        for item in &self.format.items {
+            match *item {
+                Item::Literal(ref text) => f.write_str(text)?,
+                Item::Hours { padded } => write_part(f, d.part_of_hours_abs(), padded)?,
+                Item::Minutes { padded } => write_part(f, d.part_of_minutes_abs(), padded)?,
+                Item::TotalMinutes { padded } => {
+                    write_part(f, d.part_of_total_minutes_abs(), padded)?
+                }
+                Item::Seconds { padded } => write_part(f, d.part_of_seconds_abs(), padded)?,
+                Item::Fract { places } => write_fract(f, d.unsigned_subsecs(), places)?,
+                Item::Sign { explicit } => {
+                    if d.is_negative() {
+                        f.write_char('-')?;
+                    } else if explicit {
+                        f.write_char('+')?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_part(f: &mut fmt::Formatter<'_>, value: u64, padded: bool) -> fmt::Result {
+    if padded {
+        write!(f, "{:02}", value)
+    } else {
+        write!(f, "{}", value)
+    }
+}
@@ -0,0 +1,114 @@
+#![allow(clippy::identity_op)]
+
+use crate::format::{DurationFormat, FormatError};
+use crate::{FractPartOfDuration, Hhmmss};
+
+fn check_fixed_layouts<T: Hhmmss>(d: T) {
+    let cases: [(&str, String); 15] = [
+        ("%s%M:%S", d.mmss()),
+        ("%s%M:%S.%3f", d.mmssxxx()),
+        ("%s%-M:%S", d.mss()),
+        ("%s%-M:%S.%3f", d.mssxxx()),
+        ("%s%H:%M:%S", d.hhmmss()),
+        ("%s%H:%M:%S.%3f", d.hhmmssxxx()),
+        ("%s%-H:%M:%S", d.hmmss()),
+        ("%s%-H:%M:%S.%3f", d.hmmssxxx()),
+        (
+            "%s%H:%M:%S.%6f",
+            d.hhmmss_and_fract(FractPartOfDuration::Microseconds),
+        ),
+        (
+            "%-H:%M:%S.%f",
+            d.unsigned_hmmss_and_fract(FractPartOfDuration::Nanoseconds),
+        ),
+        ("%H:%M:%S", d.unsigned_hhmmss()),
+        ("%s%H", d.fmt_hh()),
+        ("%M", d.unsigned_mm()),
+        ("%S", d.unsigned_ss()),
+        ("%3f", d.unsigned_xxx()),
+    ];
+    for (pattern, expected) in cases.iter() {
+        let format = DurationFormat::parse(pattern).unwrap();
+        assert_eq!(&d.fmt_with(&format), expected, "{}", pattern);
+    }
+}
+
+#[test]
+fn test_format_matches_fixed_layouts_std() {
+    check_fixed_layouts(std::time::Duration::new(
+        (1 * 60 + 23) * 60 + 45,
+        678_901_234,
+    ));
+    check_fixed_layouts(std::time::Duration::new(7, 5_000_000));
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_format_matches_fixed_layouts_chrono() {
+    check_fixed_layouts(
+        chrono::Duration::seconds(-((1 * 60 + 23) * 60 + 45))
+            + chrono::Duration::nanoseconds(-678_901_234),
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_format_matches_fixed_layouts_time() {
+    check_fixed_layouts(time::Duration::new(
+        -((1 * 60 + 23) * 60 + 45),
+        -678_901_234,
+    ));
+}
+
+#[test]
+fn test_format_literals_and_sign() {
+    let d = std::time::Duration::new(3 * 3600 + 4 * 60 + 5, 600_000_000);
+    let format: DurationFormat = "%+%-Hh %-Mm %-S.%1fs (100%%)".parse().unwrap();
+    assert_eq!(format.format(&d), "+3h 4m 5.6s (100%)");
+    assert_eq!(d.display_with(&format).to_string(), "+3h 4m 5.6s (100%)");
+    let format = DurationFormat::parse("T%H%M%S").unwrap();
+    assert_eq!(format.format(&d), "T030405");
+}
+
+#[test]
+fn test_format_errors() {
+    assert_eq!(
+        DurationFormat::parse("%H:%"),
+        Err(FormatError::UnexpectedEnd { position: 4 })
+    );
+    assert_eq!(
+        DurationFormat::parse("%H:%Q"),
+        Err(FormatError::UnknownSpecifier {
+            position: 4,
+            found: 'Q'
+        })
+    );
+    assert_eq!(
+        DurationFormat::parse("%-f"),
+        Err(FormatError::UnpaddableSpecifier {
+            position: 2,
+            found: 'f'
+        })
+    );
+    assert_eq!(
+        DurationFormat::parse("%H %-Q"),
+        Err(FormatError::UnknownSpecifier {
+            position: 5,
+            found: 'Q'
+        })
+    );
+    assert_eq!(
+        DurationFormat::parse("%S.%0f"),
+        Err(FormatError::InvalidPrecision {
+            position: 4,
+            precision: 0
+        })
+    );
+    assert_eq!(
+        DurationFormat::parse("%3x"),
+        Err(FormatError::UnknownSpecifier {
+            position: 2,
+            found: 'x'
+        })
+    );
+}
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FractPartOfDuration {
 	Milliseconds,
 	Microseconds,
@@ -20,3 +21,50 @@ impl FractPartOfDuration {
 		}
 	}
 }
+
+/// The number of fractional digits to output, from 1 to 9.
+///
+/// Every method taking a [`FractPartOfDuration`] also accepts a `Precision`,
+/// so layouts such as "01:23:45.6" (tenths) or "01:23:45.67" (centiseconds)
+/// are possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Precision(u8);
+
+impl Precision {
+	pub const DECISECONDS: Precision = Precision(1);
+	pub const CENTISECONDS: Precision = Precision(2);
+	pub const MILLISECONDS: Precision = Precision(3);
+	pub const MICROSECONDS: Precision = Precision(6);
+	pub const NANOSECONDS: Precision = Precision(9);
+
+	/// Returns the precision with the given number of digits, or `None` if
+	/// `digits` is not in the range [1, 9].
+	pub const fn new(digits: u8) -> Option<Precision> {
+		if digits >= 1 && digits <= 9 {
+			Some(Precision(digits))
+		} else {
+			None
+		}
+	}
+	pub fn units_per_sec(&self) -> u32 {
+		10u32.pow(self.0 as u32)
+	}
+	pub fn decimal_places(&self) -> usize {
+		self.0 as usize
+	}
+}
+
+impl From<FractPartOfDuration> for Precision {
+	fn from(part: FractPartOfDuration) -> Self {
+		Precision(part.decimal_places() as u8)
+	}
+}
+
+impl TryFrom<u8> for Precision {
+	type Error = u8;
+
+	/// Fails with the given value if it is not in the range [1, 9].
+	fn try_from(digits: u8) -> Result<Self, Self::Error> {
+		Precision::new(digits).ok_or(digits)
+	}
+}
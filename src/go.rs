@@ -0,0 +1,228 @@
+//! Go duration strings such as "1h2m3.5s", as written by Go's
+//! `time.Duration.String()` and read by `time.ParseDuration`, and the
+//! Prometheus variant "1d2h".
+//!
+//! ```
+//! use hhmmss_rs::parse::ParseHhmmss as _;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(3723, 500_000_000);
+//! assert_eq!(d.fmt_go(), "1h2m3.5s");
+//! assert_eq!(std::time::Duration::parse_go("1h2m3.5s"), Ok(d));
+//! assert_eq!(
+//!     std::time::Duration::parse_prometheus("1d2h"),
+//!     Ok(std::time::Duration::from_secs(93_600))
+//! );
+//! ```
+//!
+//! Both parsers reject durations that do not fit into Go's `time.Duration`,
+//! a signed 64-bit count of nanoseconds, with [`ParseError::Overflow`].
+
+use std::fmt::{self, Write as _};
+
+use crate::parse::{Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Writes the `digits` lowest digits of `value` after a ".", without
+/// trailing zeros. Writes nothing if they are all zero.
+fn write_fract(f: &mut fmt::Formatter<'_>, value: u64, digits: u32) -> fmt::Result {
+    let mut value = value % 10u64.pow(digits);
+    if value == 0 {
+        return Ok(());
+    }
+    let mut width = digits as usize;
+    while value.is_multiple_of(10) {
+        value /= 10;
+        width -= 1;
+    }
+    write!(f, ".{:0width$}", value, width = width)
+}
+
+/// Writes a duration like Go's `time.Duration.String()`.
+pub struct DisplayGo<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for DisplayGo<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = self.duration.to_duration_parts();
+        if parts.is_zero() {
+            return f.write_str("0s");
+        }
+        if parts.negative {
+            f.write_char('-')?;
+        }
+        let nanos = parts.nanos as u64;
+        if parts.secs == 0 {
+            // Below one second Go switches to the smallest unit that keeps
+            // the integer part non-zero.
+            return if nanos < 1_000 {
+                write!(f, "{}ns", nanos)
+            } else if nanos < 1_000_000 {
+                write!(f, "{}", nanos / 1_000)?;
+                write_fract(f, nanos, 3)?;
+                f.write_str("µs")
+            } else {
+                write!(f, "{}", nanos / 1_000_000)?;
+                write_fract(f, nanos, 6)?;
+                f.write_str("ms")
+            };
+        }
+        let hours = parts.secs / DurationParts::SECONDS_IN_A_HOUR;
+        let minutes = parts.secs / 60;
+        if hours != 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}m", minutes % 60)?;
+        }
+        write!(f, "{}", parts.secs % 60)?;
+        write_fract(f, nanos, 9)?;
+        f.write_char('s')
+    }
+}
+
+/// Checks that `nanos` fits into a Go `time.Duration`.
+fn to_go_range(nanos: u128, negative: bool) -> Result<DurationParts, ParseError> {
+    let limit = if negative {
+        1u128 << 63
+    } else {
+        (1u128 << 63) - 1
+    };
+    if nanos > limit {
+        return Err(ParseError::Overflow);
+    }
+    Ok(DurationParts::new(
+        negative && nanos != 0,
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    ))
+}
+
+/// Parses a Go duration string into [`DurationParts`], following
+/// `time.ParseDuration`.
+///
+/// The accepted grammar is an optional sign followed by "0" or by one or
+/// more decimal numbers with a unit, such as "300ms", "-1.5h" or "2h45m".
+/// The units are "ns", "us", "µs", "μs", "ms", "s", "m" and "h". Units may
+/// repeat and appear in any order, and the numbers may have any number of
+/// fractional digits; digits below a nanosecond are truncated.
+pub fn parse(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let negative = cursor.sign();
+    if cursor.rest() == "0" {
+        return Ok(DurationParts::default());
+    }
+    if cursor.is_at_end() {
+        return Err(cursor.unexpected());
+    }
+    let mut total: u128 = 0;
+    while !cursor.is_at_end() {
+        let number_pos = cursor.pos;
+        let whole = cursor.digits();
+        let fract = if cursor.eat('.') { cursor.digits() } else { "" };
+        if whole.is_empty() && fract.is_empty() {
+            cursor.pos = number_pos;
+            return Err(cursor.unexpected());
+        }
+        let unit = if cursor.eat_str("ns") {
+            1
+        } else if cursor.eat_str("us") || cursor.eat_str("µs") || cursor.eat_str("μs") {
+            1_000
+        } else if cursor.eat_str("ms") {
+            1_000_000
+        } else if cursor.eat('s') {
+            NANOS_PER_SEC
+        } else if cursor.eat('m') {
+            60 * NANOS_PER_SEC
+        } else if cursor.eat('h') {
+            3_600 * NANOS_PER_SEC
+        } else {
+            return Err(cursor.unexpected());
+        };
+        let whole: u128 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| ParseError::Overflow)?
+        };
+        // 24 digits are exact to well below a nanosecond for every unit.
+        let fract = &fract[..fract.len().min(24)];
+        let scale = 10u128.pow(fract.len() as u32);
+        let fract = if fract.is_empty() {
+            0
+        } else {
+            fract.parse::<u128>().unwrap() * unit / scale
+        };
+        total = whole
+            .checked_mul(unit)
+            .and_then(|v| v.checked_add(fract))
+            .and_then(|v| v.checked_add(total))
+            .filter(|&v| v <= 1 << 63)
+            .ok_or(ParseError::Overflow)?;
+    }
+    to_go_range(total, negative)
+}
+
+/// Parses a Prometheus duration string such as "1d2h" or "90s" into
+/// [`DurationParts`].
+///
+/// The accepted grammar is "0" or `[nY][nW][nD][nH][nM][nS][nMS]` in
+/// lower case, with whole numbers only and each unit at most once, from
+/// the largest to the smallest. A year is 365 days. Signs are not accepted.
+pub fn parse_prometheus(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if s == "0" {
+        return Ok(DurationParts::default());
+    }
+    const UNITS: [(&str, u128, Field); 7] = [
+        ("y", 365 * 86_400_000, Field::Years),
+        ("w", 7 * 86_400_000, Field::Weeks),
+        ("d", 86_400_000, Field::Days),
+        ("h", 3_600_000, Field::Hours),
+        ("ms", 1, Field::Milliseconds),
+        ("m", 60_000, Field::Minutes),
+        ("s", 1_000, Field::Seconds),
+    ];
+    // The order of the units in the string, with "ms" after "s".
+    const RANK: [usize; 7] = [0, 1, 2, 3, 6, 4, 5];
+    let mut cursor = Cursor::new(s);
+    let mut millis: u128 = 0;
+    let mut next_rank = 0;
+    while !cursor.is_at_end() {
+        let number_pos = cursor.pos;
+        let digits = cursor.digits();
+        if digits.is_empty() {
+            return Err(cursor.unexpected());
+        }
+        let unit_pos = cursor.pos;
+        let (index, (unit, factor, field)) = UNITS
+            .iter()
+            .enumerate()
+            .find(|(_, (unit, ..))| cursor.eat_str(unit))
+            .ok_or_else(|| cursor.unexpected())?;
+        let value: u64 = digits.parse().map_err(|_| ParseError::OutOfRange {
+            position: number_pos,
+            field: *field,
+            value: u64::MAX,
+        })?;
+        if RANK[index] < next_rank {
+            return Err(ParseError::UnitOutOfOrder {
+                position: unit_pos,
+                unit: (*unit).to_owned(),
+            });
+        }
+        next_rank = RANK[index] + 1;
+        millis = (value as u128)
+            .checked_mul(*factor)
+            .and_then(|v| v.checked_add(millis))
+            .ok_or(ParseError::Overflow)?;
+    }
+    to_go_range(millis.saturating_mul(1_000_000), false)
+}
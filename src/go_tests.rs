@@ -0,0 +1,243 @@
+use crate::go::{parse, parse_prometheus};
+use crate::parse::{DurationParts, Field, ParseError, ParseHhmmss};
+use crate::Hhmmss;
+
+fn nanos(n: i128) -> DurationParts {
+    DurationParts::from_nanos(n).unwrap()
+}
+
+const SECOND: i128 = 1_000_000_000;
+
+#[test]
+fn test_fmt_go() {
+    // The cases of Go's `TestDurationString`.
+    for (n, expected) in [
+        (0, "0s"),
+        (1, "1ns"),
+        (1_100, "1.1µs"),
+        (2_200_000, "2.2ms"),
+        (3_300_000_000, "3.3s"),
+        (4 * 60 * SECOND + 5 * SECOND, "4m5s"),
+        (4 * 60 * SECOND + 5_001_000_000, "4m5.001s"),
+        (
+            5 * 3600 * SECOND + 6 * 60 * SECOND + 7_001_000_000,
+            "5h6m7.001s",
+        ),
+        (8 * 60 * SECOND + 1, "8m0.000000001s"),
+        (i64::MAX as i128, "2562047h47m16.854775807s"),
+        (i64::MIN as i128, "-2562047h47m16.854775808s"),
+    ] {
+        assert_eq!(nanos(n).fmt_go(), expected);
+    }
+    assert_eq!(std::time::Duration::from_secs(3600).fmt_go(), "1h0m0s");
+    assert_eq!(std::time::Duration::from_secs(60).fmt_go(), "1m0s");
+    assert_eq!(std::time::Duration::from_micros(1000).fmt_go(), "1ms");
+    assert_eq!(DurationParts::new(true, 0, 0).fmt_go(), "0s");
+    assert_eq!(nanos(-1_500).fmt_go(), "-1.5µs");
+    #[cfg(feature = "chrono")]
+    assert_eq!(chrono::Duration::milliseconds(-700).fmt_go(), "-700ms");
+    #[cfg(feature = "time")]
+    assert_eq!(time::Duration::milliseconds(-700).fmt_go(), "-700ms");
+}
+
+#[test]
+fn test_parse_go() {
+    // The valid cases of Go's `TestParseDuration`.
+    for (s, n) in [
+        ("0", 0),
+        ("5s", 5 * SECOND),
+        ("30s", 30 * SECOND),
+        ("1478s", 1478 * SECOND),
+        ("-5s", -5 * SECOND),
+        ("+5s", 5 * SECOND),
+        ("-0", 0),
+        ("+0", 0),
+        ("5.0s", 5 * SECOND),
+        ("5.6s", 5_600_000_000),
+        ("5.s", 5 * SECOND),
+        (".5s", 500_000_000),
+        ("1.004s", 1_004_000_000),
+        ("1.0040s", 1_004_000_000),
+        ("100.00100s", 100_001_000_000),
+        ("10ns", 10),
+        ("11us", 11_000),
+        ("12µs", 12_000),
+        ("12μs", 12_000),
+        ("13ms", 13_000_000),
+        ("14s", 14 * SECOND),
+        ("15m", 15 * 60 * SECOND),
+        ("16h", 16 * 3600 * SECOND),
+        ("3h30m", 12_600 * SECOND),
+        ("10.5s4m", 250_500_000_000),
+        ("-2m3.4s", -123_400_000_000),
+        ("1h2m3s4ms5us6ns", 3_723_004_005_006),
+        ("39h9m14.425s", 140_954_425_000_000),
+        ("52763797000ns", 52_763_797_000),
+        ("0.100000000000000000000h", 360 * SECOND),
+        ("9007199254740993ns", 9_007_199_254_740_993),
+        ("9223372036854775807ns", i64::MAX as i128),
+        ("9223372036854775.807us", i64::MAX as i128),
+        ("9223372036854ms775us807ns", i64::MAX as i128),
+        ("-9223372036854775808ns", i64::MIN as i128),
+        ("-9223372036854775.808us", i64::MIN as i128),
+    ] {
+        assert_eq!(parse(s), Ok(nanos(n)), "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_go_errors() {
+    assert_eq!(parse(""), Err(ParseError::Empty));
+    for (s, position) in [("3", 1), ("-", 1), ("1h2", 3)] {
+        assert_eq!(
+            parse(s),
+            Err(ParseError::UnexpectedEnd { position }),
+            "{}",
+            s
+        );
+    }
+    for (s, position, found) in [
+        ("s", 0, 's'),
+        (".", 0, '.'),
+        ("-.", 1, '.'),
+        (".s", 0, '.'),
+        ("+.s", 1, '.'),
+        ("1d", 1, 'd'),
+        ("1hour", 2, 'o'),
+        ("1 s", 1, ' '),
+    ] {
+        assert_eq!(
+            parse(s),
+            Err(ParseError::UnexpectedChar { position, found }),
+            "{}",
+            s
+        );
+    }
+    for s in [
+        "9223372036854775810ns",
+        "9223372036854775808ns",
+        "-9223372036854775809ns",
+        "9223372036854775.808us",
+        "9223372036854ms775us808ns",
+        "3000000h",
+        "99999999999999999999999999999999999999999s",
+    ] {
+        assert_eq!(parse(s), Err(ParseError::Overflow), "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_go_into_durations() {
+    assert_eq!(
+        std::time::Duration::parse_go("1h30m"),
+        Ok(std::time::Duration::from_secs(5400))
+    );
+    assert_eq!(
+        std::time::Duration::parse_go("-2m"),
+        Err(ParseError::Negative)
+    );
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        chrono::Duration::parse_go("-2m"),
+        Ok(chrono::Duration::minutes(-2))
+    );
+    #[cfg(feature = "time")]
+    assert_eq!(
+        time::Duration::parse_go("1.5µs"),
+        Ok(time::Duration::nanoseconds(1500))
+    );
+}
+
+#[test]
+fn test_fmt_go_round_trip() {
+    for n in [1, 999, 1_000_001, 59 * SECOND + 1, 3_723_004_005_006, -42] {
+        let d = nanos(n);
+        assert_eq!(parse(&d.fmt_go()), Ok(d));
+    }
+}
+
+#[test]
+fn test_parse_prometheus() {
+    let ms = |n: i128| nanos(n * 1_000_000);
+    for (s, expected) in [
+        ("0", ms(0)),
+        ("0s", ms(0)),
+        ("324ms", ms(324)),
+        ("3s", ms(3_000)),
+        ("5m", ms(300_000)),
+        ("1h", ms(3_600_000)),
+        ("1d2h", ms(93_600_000)),
+        ("4d", ms(4 * 86_400_000)),
+        ("3w", ms(21 * 86_400_000)),
+        ("10y", ms(3650 * 86_400_000)),
+        ("1h30m", ms(5_400_000)),
+        ("1y1w1d1h1m1s1ms", ms(373 * 86_400_000 + 3_661_001)),
+    ] {
+        assert_eq!(parse_prometheus(s), Ok(expected), "{}", s);
+    }
+    assert_eq!(
+        std::time::Duration::parse_prometheus("1d2h"),
+        Ok(std::time::Duration::from_secs(93_600))
+    );
+
+    assert_eq!(parse_prometheus(""), Err(ParseError::Empty));
+    assert_eq!(
+        parse_prometheus("1h1d"),
+        Err(ParseError::UnitOutOfOrder {
+            position: 3,
+            unit: "d".to_owned(),
+        })
+    );
+    assert_eq!(
+        parse_prometheus("1s1s"),
+        Err(ParseError::UnitOutOfOrder {
+            position: 3,
+            unit: "s".to_owned(),
+        })
+    );
+    assert_eq!(
+        parse_prometheus("1ms1s"),
+        Err(ParseError::UnitOutOfOrder {
+            position: 4,
+            unit: "s".to_owned(),
+        })
+    );
+    assert_eq!(
+        parse_prometheus("1.5h"),
+        Err(ParseError::UnexpectedChar {
+            position: 1,
+            found: '.',
+        })
+    );
+    assert_eq!(
+        parse_prometheus("-1s"),
+        Err(ParseError::UnexpectedChar {
+            position: 0,
+            found: '-',
+        })
+    );
+    assert_eq!(
+        parse_prometheus("1H"),
+        Err(ParseError::UnexpectedChar {
+            position: 1,
+            found: 'H',
+        })
+    );
+    assert_eq!(parse_prometheus("300y"), Err(ParseError::Overflow));
+    assert_eq!(
+        parse_prometheus("1h99999999999999999999m"),
+        Err(ParseError::OutOfRange {
+            position: 2,
+            field: Field::Minutes,
+            value: u64::MAX,
+        })
+    );
+    assert_eq!(
+        parse_prometheus("99999999999999999999ms"),
+        Err(ParseError::OutOfRange {
+            position: 0,
+            field: Field::Milliseconds,
+            value: u64::MAX,
+        })
+    );
+}
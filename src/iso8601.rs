@@ -0,0 +1,174 @@
+//! ISO 8601 durations, as used by `java.time.Duration`, XML Schema
+//! `xs:duration`, iCalendar and MPEG-DASH.
+//!
+//! ```
+//! use hhmmss_rs::parse::ParseHhmmss as _;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(5025, 678_000_000);
+//! assert_eq!(d.fmt_iso8601(), "PT1H23M45.678S");
+//! assert_eq!(std::time::Duration::parse_iso8601("PT1H23M45.678S"), Ok(d));
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::display::write_fract_trimmed;
+use crate::parse::{Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+/// Writes a duration in ISO 8601 format.
+pub struct DisplayIso8601<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    pub(crate) days: bool,
+}
+
+impl<T: ?Sized> DisplayIso8601<'_, T> {
+    /// Splits whole days off the hours, e.g. "P1DT2H" instead of "PT26H".
+    pub fn with_days(mut self) -> Self {
+        self.days = true;
+        self
+    }
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for DisplayIso8601<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        if d.to_duration_parts().negative {
+            f.write_char('-')?;
+        }
+        f.write_char('P')?;
+        let (days, hours) = if self.days {
+            (d.part_of_days_abs(), d.part_of_hours_of_day_abs())
+        } else {
+            (0, d.part_of_hours_abs())
+        };
+        let minutes = d.part_of_minutes_abs();
+        let seconds = d.part_of_seconds_abs();
+        let subsecs = d.unsigned_subsecs();
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+        let has_time = hours != 0 || minutes != 0 || seconds != 0 || subsecs != 0;
+        if !has_time && days != 0 {
+            return Ok(());
+        }
+        f.write_char('T')?;
+        if hours != 0 {
+            write!(f, "{}H", hours)?;
+        }
+        if minutes != 0 {
+            write!(f, "{}M", minutes)?;
+        }
+        if seconds != 0 || subsecs != 0 || !has_time {
+            write!(f, "{}", seconds)?;
+            if subsecs != 0 {
+                f.write_char('.')?;
+                write_fract_trimmed(f, subsecs)?;
+            }
+            f.write_char('S')?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses an ISO 8601 duration into [`DurationParts`].
+///
+/// The accepted grammar is `[+-]P[nY][nM][nW][nD][T[nH][nM][nS]]`, case
+/// insensitive, where:
+///
+/// - weeks are seven days long; years and months have no fixed length and
+///   are only accepted with a value of zero,
+/// - the last component may have up to nine fractional digits after "." or
+///   ",",
+/// - each component may carry its own sign, as `java.time.Duration` writes
+///   "PT-1H-30M".
+pub fn parse(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let negative = cursor.sign();
+    if !cursor.eat('P') && !cursor.eat('p') {
+        return Err(cursor.unexpected());
+    }
+    let mut total: i128 = 0;
+    let mut in_time = false;
+    let mut rank = 0;
+    let mut components = 0;
+    let mut time_components = 0;
+    let mut fractional = false;
+    while !cursor.is_at_end() {
+        if !in_time && (cursor.eat('T') || cursor.eat('t')) {
+            in_time = true;
+            continue;
+        }
+        if fractional {
+            return Err(cursor.unexpected());
+        }
+        let component_negative = cursor.sign();
+        let number_pos = cursor.pos;
+        let digits = cursor.digits();
+        if digits.is_empty() {
+            return Err(cursor.unexpected());
+        }
+        let fraction = match cursor.peek() {
+            Some(separator @ ('.' | ',')) => {
+                fractional = true;
+                cursor.fraction_after(separator)?
+            }
+            _ => 0,
+        };
+        let unit_pos = cursor.pos;
+        let (unit_rank, secs, unit, field) =
+            match (in_time, cursor.peek().map(|c| c.to_ascii_uppercase())) {
+                (false, Some('Y')) => (1, None, "Y", Field::Years),
+                (false, Some('M')) => (2, None, "M", Field::Months),
+                (false, Some('W')) => (3, Some(7 * 86_400), "W", Field::Weeks),
+                (false, Some('D')) => (4, Some(86_400), "D", Field::Days),
+                (true, Some('H')) => (5, Some(3_600), "H", Field::Hours),
+                (true, Some('M')) => (6, Some(60), "M", Field::Minutes),
+                (true, Some('S')) => (7, Some(1), "S", Field::Seconds),
+                _ => return Err(cursor.unexpected()),
+            };
+        cursor.bump();
+        let whole: u64 = digits.parse().map_err(|_| ParseError::OutOfRange {
+            position: number_pos,
+            field,
+            value: u64::MAX,
+        })?;
+        if unit_rank <= rank {
+            return Err(ParseError::UnitOutOfOrder {
+                position: unit_pos,
+                unit: unit.to_owned(),
+            });
+        }
+        rank = unit_rank;
+        let value = match secs {
+            Some(secs) => (whole as i128)
+                .checked_mul(secs * 1_000_000_000)
+                .and_then(|v| v.checked_add(fraction as i128 * secs))
+                .ok_or(ParseError::Overflow)?,
+            None if whole == 0 && fraction == 0 => 0,
+            None => {
+                return Err(ParseError::UnsupportedUnit {
+                    position: unit_pos,
+                    unit: unit.to_owned(),
+                })
+            }
+        };
+        total = if component_negative {
+            total.checked_sub(value)
+        } else {
+            total.checked_add(value)
+        }
+        .ok_or(ParseError::Overflow)?;
+        components += 1;
+        if in_time {
+            time_components += 1;
+        }
+    }
+    if components == 0 || (in_time && time_components == 0) {
+        return Err(ParseError::UnexpectedEnd { position: s.len() });
+    }
+    DurationParts::from_nanos(if negative { -total } else { total })
+}
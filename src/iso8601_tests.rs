@@ -0,0 +1,144 @@
+use crate::iso8601::parse;
+use crate::parse::{DurationParts, ParseError, ParseHhmmss};
+use crate::Hhmmss;
+
+#[test]
+fn test_fmt_iso8601_std() {
+    let d = std::time::Duration::new(5025, 678_000_000);
+    assert_eq!(d.fmt_iso8601(), "PT1H23M45.678S");
+    assert_eq!(std::time::Duration::new(0, 0).fmt_iso8601(), "PT0S");
+    assert_eq!(std::time::Duration::new(0, 0).fmt_iso8601_days(), "PT0S");
+    assert_eq!(
+        std::time::Duration::new(0, 1).fmt_iso8601(),
+        "PT0.000000001S"
+    );
+    assert_eq!(std::time::Duration::new(3600, 0).fmt_iso8601(), "PT1H");
+    assert_eq!(
+        std::time::Duration::new(60, 500_000_000).fmt_iso8601(),
+        "PT1M0.5S"
+    );
+
+    let d = std::time::Duration::new(26 * 3600 + 4, 0);
+    assert_eq!(d.fmt_iso8601(), "PT26H4S");
+    assert_eq!(d.fmt_iso8601_days(), "P1DT2H4S");
+    assert_eq!(
+        std::time::Duration::new(3 * 86_400, 0).fmt_iso8601_days(),
+        "P3D"
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_fmt_iso8601_chrono() {
+    let d = -(chrono::Duration::seconds(5025) + chrono::Duration::milliseconds(678));
+    assert_eq!(d.fmt_iso8601(), "-PT1H23M45.678S");
+    assert_eq!(chrono::Duration::parse_iso8601(&d.fmt_iso8601()), Ok(d));
+    assert_eq!(chrono::Duration::parse_iso8601("PT-1H-23M-45.678S"), Ok(d));
+    let d = chrono::Duration::milliseconds(-700);
+    assert_eq!(d.fmt_iso8601(), "-PT0.7S");
+    assert_eq!(chrono::Duration::parse_iso8601(&d.fmt_iso8601()), Ok(d));
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_parse_iso8601_time() {
+    let d = time::Duration::parse_iso8601("-P1DT2H").unwrap();
+    assert_eq!(d, time::Duration::hours(-26));
+    assert_eq!(d.fmt_iso8601_days(), "-P1DT2H");
+    let d = time::Duration::parse_iso8601("-PT0.7S").unwrap();
+    assert_eq!(d, time::Duration::milliseconds(-700));
+    assert_eq!(d.fmt_iso8601(), "-PT0.7S");
+}
+
+#[test]
+fn test_parse_iso8601() {
+    assert_eq!(
+        parse("PT1H23M45.678S"),
+        Ok(DurationParts::new(false, 5025, 678_000_000))
+    );
+    assert_eq!(
+        parse("pt1h23m45,678s"),
+        Ok(DurationParts::new(false, 5025, 678_000_000))
+    );
+    assert_eq!(parse("P2W"), Ok(DurationParts::new(false, 14 * 86_400, 0)));
+    assert_eq!(
+        parse("P1W1DT1S"),
+        Ok(DurationParts::new(false, 8 * 86_400 + 1, 0))
+    );
+    assert_eq!(parse("P0Y0M1D"), Ok(DurationParts::new(false, 86_400, 0)));
+    assert_eq!(parse("+PT0S"), Ok(DurationParts::default()));
+    assert_eq!(parse("PT1.5H"), Ok(DurationParts::new(false, 5400, 0)));
+    assert_eq!(parse("PT0.000000001S"), Ok(DurationParts::new(false, 0, 1)));
+    assert_eq!(parse("-PT-1M"), Ok(DurationParts::new(false, 60, 0)));
+    assert_eq!(parse("PT1H-1S"), Ok(DurationParts::new(false, 3599, 0)));
+    assert_eq!(
+        std::time::Duration::parse_iso8601("PT1M30S"),
+        Ok(std::time::Duration::from_secs(90))
+    );
+}
+
+#[test]
+fn test_parse_iso8601_errors() {
+    assert_eq!(parse(""), Err(ParseError::Empty));
+    assert_eq!(parse("P"), Err(ParseError::UnexpectedEnd { position: 1 }));
+    assert_eq!(
+        parse("P1DT"),
+        Err(ParseError::UnexpectedEnd { position: 4 })
+    );
+    assert_eq!(
+        parse("1H"),
+        Err(ParseError::UnexpectedChar {
+            position: 0,
+            found: '1'
+        })
+    );
+    assert_eq!(
+        parse("PT1D"),
+        Err(ParseError::UnexpectedChar {
+            position: 3,
+            found: 'D'
+        })
+    );
+    assert_eq!(
+        parse("P1Y"),
+        Err(ParseError::UnsupportedUnit {
+            position: 2,
+            unit: "Y".to_owned()
+        })
+    );
+    assert_eq!(
+        parse("PT1S2H"),
+        Err(ParseError::UnitOutOfOrder {
+            position: 5,
+            unit: "H".to_owned()
+        })
+    );
+    assert_eq!(
+        parse("PT1.5M3S"),
+        Err(ParseError::UnexpectedChar {
+            position: 6,
+            found: '3'
+        })
+    );
+    assert_eq!(
+        std::time::Duration::parse_iso8601("-PT1S"),
+        Err(ParseError::Negative)
+    );
+    assert_eq!(
+        parse("P999999999999999999999W"),
+        Err(ParseError::OutOfRange {
+            position: 1,
+            field: crate::parse::Field::Weeks,
+            value: u64::MAX
+        })
+    );
+    assert_eq!(
+        parse("PT1H99999999999999999999M"),
+        Err(ParseError::OutOfRange {
+            position: 4,
+            field: crate::parse::Field::Minutes,
+            value: u64::MAX
+        })
+    );
+    assert_eq!(parse("P99999999999999999W"), Err(ParseError::Overflow));
+}
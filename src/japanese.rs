@@ -0,0 +1,137 @@
+//! Parsing of durations as typed by Japanese users.
+//!
+//! Full-width digits and punctuation are normalized first, so "１時間２３分"
+//! and "０１：２３：４５" are read like "1時間23分" and "01:23:45". Error
+//! positions are byte offsets into the original input.
+//!
+//! ```
+//! use hhmmss_rs::parse::ParseHhmmss as _;
+//!
+//! let d = std::time::Duration::parse_japanese("１時間２３分").unwrap();
+//! assert_eq!(d, std::time::Duration::new(4980, 0));
+//! let d = std::time::Duration::parse_japanese("1時間半").unwrap();
+//! assert_eq!(d, std::time::Duration::new(5400, 0));
+//! let d = std::time::Duration::parse_japanese("０１：２３：４５").unwrap();
+//! assert_eq!(d, std::time::Duration::new(5025, 0));
+//! ```
+
+use crate::parse::{Cursor, DurationParts, Field, ParseError};
+
+const UNITS: [(&str, u128, Field); 4] = [
+    ("日", 86_400_000_000_000, Field::Days),
+    ("時間", 3_600_000_000_000, Field::Hours),
+    ("分", 60_000_000_000, Field::Minutes),
+    ("秒", 1_000_000_000, Field::Seconds),
+];
+
+/// Parses a Japanese duration string into [`DurationParts`].
+///
+/// Two forms are accepted after normalizing full-width characters:
+///
+/// - units marked in kanji, largest first: "1日2時間3分4秒", "45秒",
+///   "1.5時間". The last unit may be followed by "半" for an extra half of
+///   it, as in "1時間半" (90 minutes). Spaces between units are ignored.
+/// - every layout of [`parse::parse`](crate::parse::parse), such as
+///   "01:23:45" or "1:23.5".
+///
+/// Both forms take an optional sign.
+pub fn parse(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let (normalized, origins) = normalize(s);
+    let result = if normalized.contains(['日', '時', '分', '秒', '半']) {
+        parse_units(&normalized)
+    } else {
+        crate::parse::parse(&normalized)
+    };
+    result.map_err(
+        |error| match error.map_position(|position| origins[position]) {
+            // Report the character as typed rather than its normalized form.
+            ParseError::UnexpectedChar { position, .. } => ParseError::UnexpectedChar {
+                position,
+                found: s[position..].chars().next().unwrap_or_default(),
+            },
+            error => error,
+        },
+    )
+}
+
+/// Maps full-width ASCII variants, the ideographic space and the minus sign
+/// to ASCII. Returns the normalized string and, for each of its byte
+/// offsets, the matching byte offset in `s`.
+fn normalize(s: &str) -> (String, Vec<usize>) {
+    let mut normalized = String::with_capacity(s.len());
+    let mut origins = Vec::with_capacity(s.len() + 1);
+    for (position, c) in s.char_indices() {
+        let c = match c {
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            '\u{2212}' => '-',
+            _ => c,
+        };
+        normalized.push(c);
+        for _ in 0..c.len_utf8() {
+            origins.push(position);
+        }
+    }
+    origins.push(s.len());
+    (normalized, origins)
+}
+
+fn skip_spaces(cursor: &mut Cursor) {
+    while cursor.eat(' ') {}
+}
+
+fn parse_units(s: &str) -> Result<DurationParts, ParseError> {
+    let mut cursor = Cursor::new(s);
+    skip_spaces(&mut cursor);
+    let negative = cursor.sign();
+    let mut total: u128 = 0;
+    let mut rank = 0;
+    let mut last = false;
+    loop {
+        skip_spaces(&mut cursor);
+        if cursor.is_at_end() {
+            break;
+        }
+        if last {
+            return Err(cursor.unexpected());
+        }
+        let number_pos = cursor.pos;
+        let digits = cursor.digits();
+        if digits.is_empty() {
+            return Err(cursor.unexpected());
+        }
+        let fraction = cursor.fraction()?;
+        let unit_pos = cursor.pos;
+        let Some(unit_rank) = UNITS.iter().position(|(name, ..)| cursor.eat_str(name)) else {
+            return Err(cursor.unexpected());
+        };
+        let (name, nanos, field) = UNITS[unit_rank];
+        let whole: u64 = digits.parse().map_err(|_| ParseError::OutOfRange {
+            position: number_pos,
+            field,
+            value: u64::MAX,
+        })?;
+        if unit_rank < rank {
+            return Err(ParseError::UnitOutOfOrder {
+                position: unit_pos,
+                unit: name.to_owned(),
+            });
+        }
+        rank = unit_rank + 1;
+        total += whole as u128 * nanos + fraction as u128 * (nanos / 1_000_000_000);
+        if fraction != 0 {
+            last = true;
+        } else if cursor.eat('半') {
+            total += nanos / 2;
+            last = true;
+        }
+    }
+    if rank == 0 {
+        return Err(ParseError::UnexpectedEnd { position: s.len() });
+    }
+    let total = i128::try_from(total).map_err(|_| ParseError::Overflow)?;
+    DurationParts::from_nanos(if negative { -total } else { total })
+}
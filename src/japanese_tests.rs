@@ -0,0 +1,133 @@
+use crate::japanese::parse;
+use crate::parse::{DurationParts, Field, ParseError, ParseHhmmss};
+
+#[test]
+fn test_parse_japanese_units() {
+    assert_eq!(
+        parse("１時間２３分"),
+        Ok(DurationParts::new(false, 4980, 0))
+    );
+    assert_eq!(parse("45秒"), Ok(DurationParts::new(false, 45, 0)));
+    assert_eq!(parse("1時間半"), Ok(DurationParts::new(false, 5400, 0)));
+    assert_eq!(parse("2分半"), Ok(DurationParts::new(false, 150, 0)));
+    assert_eq!(parse("1日半"), Ok(DurationParts::new(false, 129_600, 0)));
+    assert_eq!(
+        parse("1日2時間3分4秒"),
+        Ok(DurationParts::new(false, 93_784, 0))
+    );
+    assert_eq!(
+        parse("1時間23分45.678秒"),
+        Ok(DurationParts::new(false, 5025, 678_000_000))
+    );
+    assert_eq!(
+        parse("１時間　２３分　４５．６７８秒"),
+        Ok(DurationParts::new(false, 5025, 678_000_000))
+    );
+    assert_eq!(parse("1.5時間"), Ok(DurationParts::new(false, 5400, 0)));
+    assert_eq!(parse("90分"), Ok(DurationParts::new(false, 5400, 0)));
+    assert_eq!(parse("－３０秒"), Ok(DurationParts::new(true, 30, 0)));
+    assert_eq!(parse("−1分"), Ok(DurationParts::new(true, 60, 0)));
+}
+
+#[test]
+fn test_parse_japanese_clock() {
+    assert_eq!(
+        parse("０１：２３：４５"),
+        Ok(DurationParts::new(false, 5025, 0))
+    );
+    assert_eq!(
+        parse("１：２３．５"),
+        Ok(DurationParts::new(false, 83, 500_000_000))
+    );
+    assert_eq!(parse("01:23:45"), Ok(DurationParts::new(false, 5025, 0)));
+}
+
+#[test]
+fn test_parse_japanese_errors() {
+    assert_eq!(parse(""), Err(ParseError::Empty));
+    // "１時" is three bytes per character; the error points at "x".
+    assert_eq!(
+        parse("１時間x"),
+        Err(ParseError::UnexpectedChar {
+            position: 9,
+            found: 'x'
+        })
+    );
+    assert_eq!(
+        parse("１時"),
+        Err(ParseError::UnexpectedChar {
+            position: 3,
+            found: '時'
+        })
+    );
+    assert_eq!(
+        parse("５分１時間"),
+        Err(ParseError::UnitOutOfOrder {
+            position: 9,
+            unit: "時間".to_owned()
+        })
+    );
+    assert_eq!(
+        parse("1時間半5分"),
+        Err(ParseError::UnexpectedChar {
+            position: 10,
+            found: '5'
+        })
+    );
+    assert_eq!(
+        parse("０１：６０：００"),
+        Err(ParseError::OutOfRange {
+            position: 9,
+            field: Field::Minutes,
+            value: 60
+        })
+    );
+    assert_eq!(
+        parse("1時間99999999999999999999分"),
+        Err(ParseError::OutOfRange {
+            position: 7,
+            field: Field::Minutes,
+            value: u64::MAX
+        })
+    );
+    assert_eq!(
+        parse("０１：２３："),
+        Err(ParseError::UnexpectedEnd { position: 18 })
+    );
+    assert_eq!(
+        parse("０１：２３；４５"),
+        Err(ParseError::UnexpectedChar {
+            position: 15,
+            found: '；'
+        })
+    );
+    assert_eq!(
+        parse("半"),
+        Err(ParseError::UnexpectedChar {
+            position: 0,
+            found: '半'
+        })
+    );
+    assert_eq!(
+        std::time::Duration::parse_japanese("－１分"),
+        Err(ParseError::Negative)
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_parse_japanese_chrono() {
+    assert_eq!(
+        chrono::Duration::parse_japanese("－１時間半"),
+        Ok(-chrono::Duration::minutes(90))
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_parse_japanese_time() {
+    assert_eq!(
+        time::Duration::parse_japanese("２３分４５秒"),
+        Ok(time::Duration::seconds(1425))
+    );
+}
@@ -1,11 +1,120 @@
-use fracts::FractPartOfDuration;
-
+use ass::DisplayAss;
+use cuesheet::DisplayCdFrames;
+use display::{
+    Clock, Component, DayClock, DayStyle, Fract, Leading, Sign, Smart, TruncationError, Unit,
+};
+use format::{DurationFormat, Formatted};
+use fracts::{FractPartOfDuration, Precision};
+use go::DisplayGo;
+use iso8601::DisplayIso8601;
+use parse::DurationParts;
+use postgres::{DisplayPostgres, IntervalStyle};
+use round::{RoundTo, Rounding};
+use systemd::DisplaySystemd;
+use worded::{Worded, WordedFormat};
+
+pub mod ass;
+pub mod chapters;
+pub mod cuesheet;
+pub mod display;
+pub mod format;
 pub mod fracts;
+pub mod go;
+pub mod iso8601;
+pub mod japanese;
+pub mod locale;
+pub mod lrc;
+pub mod parse;
+pub mod postgres;
+pub mod rational;
+pub mod round;
+pub mod samples;
+pub mod srt;
+pub mod systemd;
+pub mod timecode;
+pub mod webvtt;
+pub mod worded;
+
+/// Formats a duration with a pattern that is validated at compile time.
+///
+/// Placeholders are `{h}`, `{hh}`, `{m}`, `{mm}`, `{s}`, `{ss}`, `{f}`,
+/// `{f1}` to `{f9}`, `{-}` and `{+}`; see [`format`](mod@format) for their runtime
+/// counterparts. The macro expands into direct calls on the [`Hhmmss`]
+/// accessors and evaluates to a `String`.
+///
+/// ```
+/// use hhmmss_rs::format_duration;
+///
+/// let d = std::time::Duration::new(5025, 678_901_234);
+/// assert_eq!(format_duration!("{-}{hh}:{mm}:{ss}.{f3}", d), "01:23:45.678");
+/// assert_eq!(format_duration!("{h}h {m}m {s}.{f1}s", d), "1h 23m 45.6s");
+/// ```
+///
+/// Typos in the pattern are compile errors:
+///
+/// ```compile_fail
+/// use hhmmss_rs::format_duration;
+///
+/// let d = std::time::Duration::new(5025, 0);
+/// format_duration!("{hh}:{mn}:{ss}", d);
+/// ```
+#[cfg(feature = "macros")]
+pub use hhmmss_rs_macros::format_duration;
+
+#[cfg(all(test, feature = "macros"))]
+extern crate self as hhmmss_rs;
 
+#[cfg(test)]
+mod ass_tests;
+#[cfg(test)]
+mod chapters_tests;
+#[cfg(test)]
+mod cuesheet_tests;
+#[cfg(test)]
+mod days_tests;
+#[cfg(test)]
+mod display_tests;
 #[cfg(test)]
 mod feature_tests;
 #[cfg(test)]
+mod format_tests;
+#[cfg(test)]
+mod go_tests;
+#[cfg(test)]
+mod iso8601_tests;
+#[cfg(test)]
+mod japanese_tests;
+#[cfg(test)]
+mod locale_tests;
+#[cfg(all(test, feature = "macros"))]
+mod macro_tests;
+#[cfg(test)]
+mod parse_tests;
+#[cfg(test)]
+mod postgres_tests;
+#[cfg(test)]
+mod precision_tests;
+#[cfg(test)]
+mod rational_tests;
+#[cfg(test)]
+mod round_tests;
+#[cfg(test)]
+mod samples_tests;
+#[cfg(test)]
+mod srt_tests;
+#[cfg(test)]
+mod systemd_tests;
+#[cfg(test)]
+#[allow(clippy::identity_op, clippy::bool_assert_comparison)]
 mod tests;
+#[cfg(test)]
+mod timecode_tests;
+#[cfg(test)]
+mod total_minutes_tests;
+#[cfg(test)]
+mod webvtt_tests;
+#[cfg(test)]
+mod worded_tests;
 
 #[allow(private_bounds)]
 pub trait Hhmmss: HasSubseconds {
@@ -14,8 +123,10 @@ pub trait Hhmmss: HasSubseconds {
     const MILLISECONDS_IN_A_SECOND: u64 = 1_000;
     const SECONDS_IN_A_MINUTE: u64 = 60;
     const MINUTES_IN_AN_HOUR: u64 = 60;
+    const HOURS_IN_A_DAY: u64 = 24;
 
     const SECONDS_IN_A_HOUR: u64 = Self::SECONDS_IN_A_MINUTE * Self::MINUTES_IN_AN_HOUR;
+    const SECONDS_IN_A_DAY: u64 = Self::SECONDS_IN_A_HOUR * Self::HOURS_IN_A_DAY;
     const NANOSECONDS_IN_A_SECOND: u64 = Self::NANOSECONDS_IN_A_MICROSECOND
         * Self::MICROSECONDS_IN_A_MILLISECOND
         * Self::MILLISECONDS_IN_A_SECOND;
@@ -24,16 +135,15 @@ pub trait Hhmmss: HasSubseconds {
     /// "-" for negative durations, and an empty string for non-negative
     /// durations. The output is either "-" or "".
     fn get_sign(&self) -> String {
-        if self.is_negative() {
-            "-".to_owned()
-        } else {
-            "".to_owned()
-        }
+        self.display_sign().to_string()
     }
 
     /// Checks if the duration is negative.
     /// Returns `true` if negative, otherwise `false`.
     fn is_negative(&self) -> bool;
+    /// Returns the absolute value of the days part of the duration.
+    /// The output is in the range [0, ∞).
+    fn part_of_days_abs(&self) -> u64;
     /// Returns the absolute value of the hours part of the duration.
     /// The output is in the range [0, ∞).
     fn part_of_hours_abs(&self) -> u64;
@@ -52,6 +162,9 @@ pub trait Hhmmss: HasSubseconds {
     /// Returns the absolute value of the nanoseconds part of the duration.
     /// The output is in the range [0, 999].
     fn part_of_nanoseconds_abs(&self) -> u64;
+    /// Returns the days part of the duration.
+    /// The output can be negative or positive, depending on the duration.
+    fn part_of_days(&self) -> i64;
     /// Returns the hours part of the duration.
     /// The output can be negative or positive, depending on the duration.
     fn part_of_hours(&self) -> i64;
@@ -71,238 +184,642 @@ pub trait Hhmmss: HasSubseconds {
     /// The output is in the range [-999, 999].
     fn part_of_nanoseconds(&self) -> i64;
 
+    /// Returns the absolute value of the hours part of the duration that
+    /// does not make up a whole day.
+    /// The output is in the range [0, 23].
+    fn part_of_hours_of_day_abs(&self) -> u64 {
+        self.part_of_hours_abs() % Self::HOURS_IN_A_DAY
+    }
+    /// Returns the hours part of the duration that does not make up a whole
+    /// day.
+    /// The output is in the range [-23, 23].
+    fn part_of_hours_of_day(&self) -> i64 {
+        self.part_of_hours() % Self::HOURS_IN_A_DAY as i64
+    }
+
+    /// Returns the absolute value of the duration in whole minutes, including
+    /// the minutes that make up whole hours.
+    /// The output is in the range [0, ∞).
+    fn part_of_total_minutes_abs(&self) -> u64 {
+        self.part_of_hours_abs() * Self::MINUTES_IN_AN_HOUR + self.part_of_minutes_abs()
+    }
+    /// Returns the duration in whole minutes, including the minutes that make
+    /// up whole hours.
+    /// The output can be negative or positive, depending on the duration.
+    fn part_of_total_minutes(&self) -> i64 {
+        self.part_of_hours() * Self::MINUTES_IN_AN_HOUR as i64 + self.part_of_minutes()
+    }
+
+    /// Returns an adapter writing "-" for negative durations and nothing
+    /// otherwise.
+    fn display_sign(&self) -> Sign<'_, Self> {
+        Sign { duration: self }
+    }
+    /// Returns an adapter writing the hours part as "-HH" or "HH".
+    fn display_hh(&self) -> Component<'_, Self> {
+        Component {
+            duration: self,
+            unit: Unit::Hours,
+            signed: true,
+        }
+    }
+    /// Returns an adapter writing the minutes part as "-MM" or "MM".
+    fn display_mm(&self) -> Component<'_, Self> {
+        Component {
+            duration: self,
+            unit: Unit::Minutes,
+            signed: true,
+        }
+    }
+    /// Returns an adapter writing the seconds part as "-SS" or "SS".
+    fn display_ss(&self) -> Component<'_, Self> {
+        Component {
+            duration: self,
+            unit: Unit::Seconds,
+            signed: true,
+        }
+    }
+    /// Returns an adapter writing the milliseconds part as "-xxx" or "xxx".
+    fn display_xxx(&self) -> Component<'_, Self> {
+        Component {
+            duration: self,
+            unit: Unit::Milliseconds,
+            signed: true,
+        }
+    }
+    /// Returns an adapter writing the fractional seconds without a dot,
+    /// truncated to the specified fractional part.
+    fn display_fract(&self, included: impl Into<Precision>) -> Fract<'_, Self> {
+        Fract {
+            duration: self,
+            included: included.into(),
+        }
+    }
+    /// Returns an adapter writing "-MM:SS" or "MM:SS".
+    fn display_mmss(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::Minutes, true)
+    }
+    /// Returns an adapter writing "-MM:SS.xxx" or "MM:SS.xxx".
+    fn display_mmssxxx(&self) -> Clock<'_, Self> {
+        self.display_mmss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing "MM:SS" with a sign and the specified
+    /// fractional part.
+    fn display_mmss_and_fract(&self, included: impl Into<Precision>) -> Clock<'_, Self> {
+        self.display_mmss().with_fract(included.into())
+    }
+    /// Returns an adapter writing "-M:SS" or "M:SS".
+    fn display_mss(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::Minutes, false)
+    }
+    /// Returns an adapter writing "-M:SS.xxx" or "M:SS.xxx".
+    fn display_mssxxx(&self) -> Clock<'_, Self> {
+        self.display_mss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing "M:SS" with a sign and the specified
+    /// fractional part.
+    fn display_mss_and_fract(&self, included: impl Into<Precision>) -> Clock<'_, Self> {
+        self.display_mss().with_fract(included.into())
+    }
+    /// Returns an adapter writing the total minutes as "-MM:SS" or "MM:SS",
+    /// where the minutes do not wrap at 60.
+    fn display_total_mmss(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::TotalMinutes, true)
+    }
+    /// Returns an adapter writing the total minutes as "-MM:SS.xxx" or
+    /// "MM:SS.xxx", where the minutes do not wrap at 60.
+    fn display_total_mmssxxx(&self) -> Clock<'_, Self> {
+        self.display_total_mmss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing the total minutes as "MM:SS" with a sign
+    /// and the specified fractional part.
+    fn display_total_mmss_and_fract(&self, included: impl Into<Precision>) -> Clock<'_, Self> {
+        self.display_total_mmss().with_fract(included.into())
+    }
+    /// Returns an adapter writing the total minutes as "-M:SS" or "M:SS",
+    /// where the minutes do not wrap at 60.
+    fn display_total_mss(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::TotalMinutes, false)
+    }
+    /// Returns an adapter writing the total minutes as "-M:SS.xxx" or
+    /// "M:SS.xxx", where the minutes do not wrap at 60.
+    fn display_total_mssxxx(&self) -> Clock<'_, Self> {
+        self.display_total_mss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing the total minutes as "M:SS" with a sign
+    /// and the specified fractional part.
+    fn display_total_mss_and_fract(&self, included: impl Into<Precision>) -> Clock<'_, Self> {
+        self.display_total_mss().with_fract(included.into())
+    }
+    /// Returns an adapter writing "-HH:MM:SS" or "HH:MM:SS".
+    fn display_hhmmss(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::Hours, true)
+    }
+    /// Returns an adapter writing "-HH:MM:SS.xxx" or "HH:MM:SS.xxx".
+    fn display_hhmmssxxx(&self) -> Clock<'_, Self> {
+        self.display_hhmmss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing "HH:MM:SS" with a sign and the specified
+    /// fractional part.
+    fn display_hhmmss_and_fract(&self, included: impl Into<Precision>) -> Clock<'_, Self> {
+        self.display_hhmmss().with_fract(included.into())
+    }
+    /// Returns an adapter writing "-H:MM:SS" or "H:MM:SS".
+    fn display_hmmss(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::Hours, false)
+    }
+    /// Returns an adapter writing "-H:MM:SS.xxx" or "H:MM:SS.xxx".
+    fn display_hmmssxxx(&self) -> Clock<'_, Self> {
+        self.display_hmmss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing "H:MM:SS" with a sign and the specified
+    /// fractional part.
+    fn display_hmmss_and_fract(&self, included: impl Into<Precision>) -> Clock<'_, Self> {
+        self.display_hmmss().with_fract(included.into())
+    }
+    /// Returns an adapter writing "-1d 02:03:04" or "1d 02:03:04". The days
+    /// are omitted for durations shorter than a day.
+    fn display_dhhmmss(&self) -> DayClock<'_, Self> {
+        DayClock::new(self, DayStyle::Suffix)
+    }
+    /// Returns an adapter writing "-1d 02:03:04.xxx" or "1d 02:03:04.xxx".
+    fn display_dhhmmssxxx(&self) -> DayClock<'_, Self> {
+        self.display_dhhmmss_and_fract(FractPartOfDuration::Milliseconds)
+    }
+    /// Returns an adapter writing "1d 02:03:04" with a sign and the
+    /// specified fractional part.
+    fn display_dhhmmss_and_fract(&self, included: impl Into<Precision>) -> DayClock<'_, Self> {
+        self.display_dhhmmss().with_fract(included.into())
+    }
+    /// Returns an adapter writing the .NET `TimeSpan` layout.
+    fn display_timespan(&self) -> DayClock<'_, Self> {
+        DayClock::new(self, DayStyle::TimeSpan)
+    }
+    /// Returns an adapter writing the Python `timedelta` layout.
+    fn display_timedelta(&self) -> DayClock<'_, Self> {
+        DayClock::new(self, DayStyle::TimeDelta)
+    }
+    /// Returns an adapter writing the ISO 8601 duration "PT1H23M45.678S".
+    fn display_iso8601(&self) -> DisplayIso8601<'_, Self> {
+        DisplayIso8601 {
+            duration: self,
+            days: false,
+        }
+    }
+    /// Returns an adapter writing the SRT timestamp "01:23:45,678".
+    fn display_srt(&self) -> Clock<'_, Self> {
+        Clock::new(self, Leading::Hours, true)
+            .with_fract(Precision::MILLISECONDS)
+            .with_decimal_separator(',')
+    }
+    /// Returns an adapter writing the WebVTT timestamp "23:45.678", or
+    /// "01:23:45.678" if the hours are not zero.
+    fn display_webvtt(&self) -> Clock<'_, Self> {
+        if self.part_of_hours_abs() == 0 {
+            self.display_mmssxxx()
+        } else {
+            self.display_hhmmssxxx()
+        }
+    }
+    /// Returns an adapter writing the ASS timestamp "1:23:45.68", rounded to
+    /// the nearest centisecond.
+    fn display_ass(&self) -> DisplayAss {
+        DisplayAss::new(self)
+    }
+    /// Returns an adapter writing the cue sheet time "83:45:50", truncated
+    /// to CD frames of 1/75 second.
+    fn display_cd_frames(&self) -> DisplayCdFrames {
+        DisplayCdFrames::new(self)
+    }
+    /// Returns an adapter writing the LRC timestamp "83:45.67", where the
+    /// minutes do not wrap at 60.
+    fn display_lrc(&self) -> Clock<'_, Self> {
+        self.display_total_mmss_and_fract(Precision::CENTISECONDS)
+    }
+    /// Returns an adapter writing the Go duration "1h2m3.5s".
+    fn display_go(&self) -> DisplayGo<'_, Self> {
+        DisplayGo { duration: self }
+    }
+    /// Returns an adapter writing the systemd time span "1h 30min 5s".
+    /// Use [`DisplaySystemd::with_accuracy`] to shorten it.
+    fn display_systemd(&self) -> DisplaySystemd<'_, Self> {
+        DisplaySystemd {
+            duration: self,
+            accuracy: 1,
+        }
+    }
+    /// Returns an adapter writing the PostgreSQL `interval` output in the
+    /// given style, e.g. "1 day 01:23:45.678".
+    fn display_postgres(&self, style: IntervalStyle) -> DisplayPostgres<'_, Self> {
+        DisplayPostgres {
+            duration: self,
+            style,
+        }
+    }
+    /// Returns an adapter writing the duration in words.
+    fn display_worded<'a>(&'a self, format: &'a WordedFormat) -> Worded<'a, Self> {
+        format.display(self)
+    }
+    /// Returns an adapter writing the output of [`Hhmmss::fmt_smart`].
+    fn display_smart(&self) -> Smart<'_, Self> {
+        Smart { duration: self }
+    }
+    /// Returns an adapter writing the duration in a compiled
+    /// [`DurationFormat`].
+    fn display_with<'a>(&'a self, format: &'a DurationFormat) -> Formatted<'a, Self> {
+        format.display(self)
+    }
+
     /// Formats the absolute value of the hours part as a two-digit string.
     /// The output is in the format "HH".
     fn unsigned_hh(&self) -> String {
-        format!("{:02}", self.part_of_hours_abs())
+        self.display_hh().unsigned().to_string()
     }
     /// Formats the absolute value of the minutes part as a two-digit string.
     /// The output is in the format "MM".
     fn unsigned_mm(&self) -> String {
-        format!("{:02}", self.part_of_minutes_abs())
+        self.display_mm().unsigned().to_string()
     }
     /// Formats the absolute value of the seconds part as a two-digit string.
     /// The output is in the format "SS".
     fn unsigned_ss(&self) -> String {
-        format!("{:02}", self.part_of_seconds_abs())
+        self.display_ss().unsigned().to_string()
     }
     /// Formats the absolute value of the milliseconds part as a three-digit
     /// string. The output is in the format "xxx".
     fn unsigned_xxx(&self) -> String {
-        format!("{:03}", self.part_of_milliseconds_abs())
+        self.display_xxx().unsigned().to_string()
     }
 
     /// Formats the hours part with a sign.
     /// The output is in the format "-HH" or "HH".
     fn fmt_hh(&self) -> String {
-        self.get_sign() + &self.unsigned_hh()
+        self.display_hh().to_string()
     }
     /// Formats the minutes part with a sign.
     /// The output is in the format "-MM" or "MM".
     fn fmt_mm(&self) -> String {
-        self.get_sign() + &self.unsigned_mm()
+        self.display_mm().to_string()
     }
     /// Formats the seconds part with a sign.
     /// The output is in the format "-SS" or "SS".
     fn fmt_ss(&self) -> String {
-        self.get_sign() + &self.unsigned_ss()
+        self.display_ss().to_string()
     }
     /// Formats the milliseconds part with a sign.
     /// The output is in the format "-xxx" or "xxx".
     fn fmt_xxx(&self) -> String {
-        self.get_sign() + &self.unsigned_xxx()
+        self.display_xxx().to_string()
     }
 
     /// Formats the absolute value of the duration as "MM:SS".
     /// The output is in the format "MM:SS".
     fn unsigned_mmss(&self) -> String {
-        format!("{}:{}", self.unsigned_mm(), self.unsigned_ss())
+        self.display_mmss().unsigned().to_string()
     }
     /// Formats the absolute value of the duration as "MM:SS.xxx".
     /// The output is in the format "MM:SS.xxx".
     fn unsigned_mmssxxx(&self) -> String {
-        format!(
-            "{}:{}.{}",
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-            self.unsigned_xxx()
-        )
+        self.display_mmssxxx().unsigned().to_string()
     }
 
     /// Formats the absolute value of the duration as "MM:SS" with fractional
     /// seconds. The output is in the format "MM:SS.xxx", "MM:SS.xxxxxx", or
     /// "MM:SS.xxxxxxxxx" depending on the specified fractional part.
-    fn unsigned_mmss_and_fract(&self, included: FractPartOfDuration) -> String {
-        format!(
-            "{}:{}.{}",
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-            self.fmt_fract(included)
-        )
+    fn unsigned_mmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_mmss_and_fract(included).unsigned().to_string()
     }
     /// Formats the absolute value of the duration as "M:SS".
     /// The output is in the format "M:SS".
     fn unsigned_mss(&self) -> String {
-        format!("{}:{}", self.part_of_minutes_abs(), self.unsigned_ss())
+        self.display_mss().unsigned().to_string()
     }
     /// Formats the absolute value of the duration as "M:SS.xxx".
     /// The output is in the format "M:SS.xxx".
     fn unsigned_mssxxx(&self) -> String {
-        format!(
-            "{}:{}.{}",
-            self.part_of_minutes_abs(),
-            self.unsigned_ss(),
-            self.unsigned_xxx(),
-        )
+        self.display_mssxxx().unsigned().to_string()
     }
 
     /// Formats the absolute value of the duration as "M:SS" with fractional
     /// seconds. The output is in the format "M:SS.xxx", "M:SS.xxxxxx", or
     /// "M:SS.xxxxxxxxx" depending on the specified fractional part.
-    fn unsigned_mss_and_fract(&self, included: FractPartOfDuration) -> String {
-        format!(
-            "{}:{}.{}",
-            self.part_of_minutes_abs(),
-            self.unsigned_ss(),
-            self.fmt_fract(included)
-        )
+    fn unsigned_mss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_mss_and_fract(included).unsigned().to_string()
     }
     /// Formats the absolute value of the duration as "HH:MM:SS".
     /// The output is in the format "HH:MM:SS".
     fn unsigned_hhmmss(&self) -> String {
-        format!(
-            "{}:{}:{}",
-            self.unsigned_hh(),
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-        )
+        self.display_hhmmss().unsigned().to_string()
     }
 
     /// Formats the absolute value of the duration as "HH:MM:SS.xxx".
     /// The output is in the format "HH:MM:SS.xxx".
     fn unsigned_hhmmssxxx(&self) -> String {
-        format!(
-            "{}:{}:{}.{}",
-            self.unsigned_hh(),
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-            self.unsigned_xxx()
-        )
+        self.display_hhmmssxxx().unsigned().to_string()
     }
 
     /// Formats the absolute value of the duration as "HH:MM:SS" with fractional
     /// seconds. The output is in the format "HH:MM:SS.xxx", "HH:MM:SS.xxxxxx",
     /// or "HH:MM:SS.xxxxxxxxx" depending on the specified fractional part.
-    fn unsigned_hhmmss_and_fract(&self, included: FractPartOfDuration) -> String {
-        format!(
-            "{}:{}:{}.{}",
-            self.unsigned_hh(),
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-            self.fmt_fract(included)
-        )
+    fn unsigned_hhmmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_hhmmss_and_fract(included)
+            .unsigned()
+            .to_string()
     }
     /// Formats the absolute value of the duration as "H:MM:SS".
     /// The output is in the format "H:MM:SS".
     fn unsigned_hmmss(&self) -> String {
-        format!(
-            "{}:{}:{}",
-            self.part_of_hours_abs(),
-            self.unsigned_mm(),
-            self.unsigned_ss()
-        )
+        self.display_hmmss().unsigned().to_string()
     }
     /// Formats the absolute value of the duration as "H:MM:SS.xxx".
     /// The output is in the format "H:MM:SS.xxx".
     fn unsigned_hmmssxxx(&self) -> String {
-        format!(
-            "{}:{}:{}.{}",
-            self.part_of_hours_abs(),
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-            self.unsigned_xxx()
-        )
+        self.display_hmmssxxx().unsigned().to_string()
     }
 
     /// Formats the absolute value of the duration as "H:MM:SS" with fractional
     /// seconds. The output is in the format "H:MM:SS.xxx", "H:MM:SS.xxxxxx",
     /// or "H:MM:SS.xxxxxxxxx" depending on the specified fractional part.
-    fn unsigned_hmmss_and_fract(&self, included: FractPartOfDuration) -> String {
-        format!(
-            "{}:{}:{}.{}",
-            self.part_of_hours_abs(),
-            self.unsigned_mm(),
-            self.unsigned_ss(),
-            self.fmt_fract(included)
-        )
+    fn unsigned_hmmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_hmmss_and_fract(included)
+            .unsigned()
+            .to_string()
     }
     /// Formats the duration as "MM:SS" with a sign.
     /// The output is in the format "-MM:SS" or "MM:SS".
     fn mmss(&self) -> String {
-        self.get_sign() + &self.unsigned_mmss()
+        self.display_mmss().to_string()
     }
     /// Formats the duration as "MM:SS.xxx" with a sign.
     /// The output is in the format "-MM:SS.xxx" or "MM:SS.xxx".
     fn mmssxxx(&self) -> String {
-        self.get_sign() + &self.unsigned_mmssxxx()
+        self.display_mmssxxx().to_string()
     }
     /// Formats the duration as "MM:SS" with fractional seconds and a sign.
     /// The output is in the format "-MM:SS.xxx", "-MM:SS.xxxxxx", or
     /// "-MM:SS.xxxxxxxxx" (or without "-" for positive durations) depending on
     /// the specified fractional part.
-    fn mmss_and_fract(&self, included: FractPartOfDuration) -> String {
-        self.get_sign() + &self.unsigned_mmss_and_fract(included)
+    fn mmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_mmss_and_fract(included).to_string()
     }
     /// Formats the duration as "M:SS" with a sign.
     /// The output is in the format "-M:SS" or "M:SS".
     fn mss(&self) -> String {
-        self.get_sign() + &self.unsigned_mss()
+        self.display_mss().to_string()
     }
     /// Formats the duration as "M:SS.xxx" with a sign.
     /// The output is in the format "-M:SS.xxx" or "M:SS.xxx".
     fn mssxxx(&self) -> String {
-        self.get_sign() + &self.unsigned_mssxxx()
+        self.display_mssxxx().to_string()
     }
     /// Formats the duration as "M:SS" with fractional seconds and a sign.
     /// The output is in the format "-M:SS.xxx", "-M:SS.xxxxxx", or
     /// "-M:SS.xxxxxxxxx" (or without "-" for positive durations) depending on
     /// the specified fractional part.
-    fn mss_and_fract(&self, included: FractPartOfDuration) -> String {
-        self.get_sign() + &self.unsigned_mss_and_fract(included)
+    fn mss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_mss_and_fract(included).to_string()
+    }
+    /// Formats the duration as "MM:SS" with a sign, or fails if the duration
+    /// is one hour or longer instead of dropping the hours.
+    /// The output is in the format "-MM:SS" or "MM:SS".
+    fn checked_mmss(&self) -> Result<String, TruncationError> {
+        Ok(self.display_mmss().checked()?.to_string())
+    }
+    /// Formats the duration as "MM:SS.xxx" with a sign, or fails if the
+    /// duration is one hour or longer instead of dropping the hours.
+    /// The output is in the format "-MM:SS.xxx" or "MM:SS.xxx".
+    fn checked_mmssxxx(&self) -> Result<String, TruncationError> {
+        Ok(self.display_mmssxxx().checked()?.to_string())
+    }
+    /// Formats the duration as "MM:SS" with fractional seconds and a sign, or
+    /// fails if the duration is one hour or longer instead of dropping the
+    /// hours.
+    fn checked_mmss_and_fract(
+        &self,
+        included: impl Into<Precision>,
+    ) -> Result<String, TruncationError> {
+        Ok(self.display_mmss_and_fract(included).checked()?.to_string())
+    }
+    /// Formats the duration as "M:SS" with a sign, or fails if the duration
+    /// is one hour or longer instead of dropping the hours.
+    /// The output is in the format "-M:SS" or "M:SS".
+    fn checked_mss(&self) -> Result<String, TruncationError> {
+        Ok(self.display_mss().checked()?.to_string())
+    }
+    /// Formats the duration as "M:SS.xxx" with a sign, or fails if the
+    /// duration is one hour or longer instead of dropping the hours.
+    /// The output is in the format "-M:SS.xxx" or "M:SS.xxx".
+    fn checked_mssxxx(&self) -> Result<String, TruncationError> {
+        Ok(self.display_mssxxx().checked()?.to_string())
+    }
+    /// Formats the duration as "M:SS" with fractional seconds and a sign, or
+    /// fails if the duration is one hour or longer instead of dropping the
+    /// hours.
+    fn checked_mss_and_fract(
+        &self,
+        included: impl Into<Precision>,
+    ) -> Result<String, TruncationError> {
+        Ok(self.display_mss_and_fract(included).checked()?.to_string())
+    }
+    /// Formats the duration as "MM:SS" with a sign, where the minutes include
+    /// whole hours and do not wrap at 60.
+    /// The output is in the format "-MM:SS" or "MM:SS", e.g. "83:45".
+    fn total_mmss(&self) -> String {
+        self.display_total_mmss().to_string()
+    }
+    /// Formats the duration as "MM:SS.xxx" with a sign, where the minutes
+    /// include whole hours and do not wrap at 60.
+    /// The output is in the format "-MM:SS.xxx" or "MM:SS.xxx", e.g.
+    /// "83:45.678".
+    fn total_mmssxxx(&self) -> String {
+        self.display_total_mmssxxx().to_string()
+    }
+    /// Formats the duration as "MM:SS" with fractional seconds and a sign,
+    /// where the minutes include whole hours and do not wrap at 60.
+    fn total_mmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_total_mmss_and_fract(included).to_string()
+    }
+    /// Formats the duration as "M:SS" with a sign, where the minutes include
+    /// whole hours and do not wrap at 60.
+    /// The output is in the format "-M:SS" or "M:SS", e.g. "83:45".
+    fn total_mss(&self) -> String {
+        self.display_total_mss().to_string()
+    }
+    /// Formats the duration as "M:SS.xxx" with a sign, where the minutes
+    /// include whole hours and do not wrap at 60.
+    /// The output is in the format "-M:SS.xxx" or "M:SS.xxx".
+    fn total_mssxxx(&self) -> String {
+        self.display_total_mssxxx().to_string()
+    }
+    /// Formats the duration as "M:SS" with fractional seconds and a sign,
+    /// where the minutes include whole hours and do not wrap at 60.
+    fn total_mss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_total_mss_and_fract(included).to_string()
     }
     /// Formats the duration as "HH:MM:SS" with a sign.
     /// The output is in the format "-HH:MM:SS" or "HH:MM:SS".
     fn hhmmss(&self) -> String {
-        self.get_sign() + &self.unsigned_hhmmss()
+        self.display_hhmmss().to_string()
     }
     /// Formats the duration as "HH:MM:SS.xxx" with a sign.
     /// The output is in the format "-HH:MM:SS.xxx" or "HH:MM:SS.xxx".
     fn hhmmssxxx(&self) -> String {
-        self.get_sign() + &self.unsigned_hhmmssxxx()
+        self.display_hhmmssxxx().to_string()
     }
     /// Formats the duration as "HH:MM:SS" with fractional seconds and a sign.
     /// The output is in the format "-HH:MM:SS.xxx", "-HH:MM:SS.xxxxxx", or
     /// "-HH:MM:SS.xxxxxxxxx" (or without "-" for positive durations) depending
     /// on the specified fractional part.
-    fn hhmmss_and_fract(&self, included: FractPartOfDuration) -> String {
-        self.get_sign() + &self.unsigned_hhmmss_and_fract(included)
+    fn hhmmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_hhmmss_and_fract(included).to_string()
     }
     /// Formats the duration as "H:MM:SS" with a sign.
     /// The output is in the format "-H:MM:SS" or "H:MM:SS".
     fn hmmss(&self) -> String {
-        self.get_sign() + &self.unsigned_hmmss()
+        self.display_hmmss().to_string()
     }
     /// Formats the duration as "H:MM:SS.xxx" with a sign.
     /// The output is in the format "-H:MM:SS.xxx" or "H:MM:SS.xxx".
     fn hmmssxxx(&self) -> String {
-        self.get_sign() + &self.unsigned_hmmssxxx()
+        self.display_hmmssxxx().to_string()
     }
     /// Formats the duration as "H:MM:SS" with fractional seconds and a sign.
     /// The output is in the format "-H:MM:SS.xxx", "-H:MM:SS.xxxxxx", or
     /// "-H:MM:SS.xxxxxxxxx" (or without "-" for positive durations) depending
     /// on the specified fractional part.
-    fn hmmss_and_fract(&self, included: FractPartOfDuration) -> String {
-        self.get_sign() + &self.unsigned_hmmss_and_fract(included)
+    fn hmmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_hmmss_and_fract(included).to_string()
+    }
+
+    /// Formats the duration as "1d 02:03:04" with a sign, moving whole days
+    /// out of the hours. The output is in the format "-1d 02:03:04" or
+    /// "1d 02:03:04", or "HH:MM:SS" for durations shorter than a day.
+    fn dhhmmss(&self) -> String {
+        self.display_dhhmmss().to_string()
+    }
+    /// Formats the duration as "1d 02:03:04.xxx" with a sign.
+    /// The output is in the format "-1d 02:03:04.xxx" or "1d 02:03:04.xxx",
+    /// or "HH:MM:SS.xxx" for durations shorter than a day.
+    fn dhhmmssxxx(&self) -> String {
+        self.display_dhhmmssxxx().to_string()
+    }
+    /// Formats the duration as "1d 02:03:04" with fractional seconds and a
+    /// sign, depending on the specified fractional part.
+    fn dhhmmss_and_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_dhhmmss_and_fract(included).to_string()
+    }
+    /// Formats the duration like .NET's `TimeSpan.ToString()`.
+    /// The output is in the format `[-][d.]hh:mm:ss[.fffffff]`, where the
+    /// seven fractional digits only appear if they are not all zero.
+    fn fmt_timespan(&self) -> String {
+        self.display_timespan().to_string()
+    }
+    /// Formats the duration like Python's `str(timedelta)`.
+    /// The output is in the format `[-][D day[s], ]H:MM:SS[.ffffff]`, where
+    /// the six fractional digits only appear if they are not all zero.
+    /// Unlike Python, negative durations are written as the sign followed by
+    /// the absolute value, e.g. "-1 day, 2:03:04".
+    fn fmt_timedelta(&self) -> String {
+        self.display_timedelta().to_string()
+    }
+
+    /// Formats the duration as an ISO 8601 duration in hours, minutes and
+    /// seconds.
+    /// The output is in the format "PT1H23M45.678S" or "-PT1H23M45.678S".
+    /// Zero components are omitted, the hours do not wrap into days and the
+    /// fractional seconds have no trailing zeros. Zero is written as "PT0S".
+    /// Non-negative durations match `java.time.Duration::toString`. Negative
+    /// durations take a single leading sign as in XML Schema, where Java
+    /// writes "PT-1H-23M-45.678S"; `Duration.parse` accepts both.
+    fn fmt_iso8601(&self) -> String {
+        self.display_iso8601().to_string()
+    }
+    /// Formats the duration as an ISO 8601 duration with whole days split off
+    /// the hours.
+    /// The output is in the format "P1DT2H3M4S" or "-P1DT2H3M4S".
+    fn fmt_iso8601_days(&self) -> String {
+        self.display_iso8601().with_days().to_string()
+    }
+
+    /// Formats the duration as an SRT timestamp.
+    /// The output is in the format "HH:MM:SS,xxx" or "-HH:MM:SS,xxx", with
+    /// the milliseconds truncated.
+    fn fmt_srt(&self) -> String {
+        self.display_srt().to_string()
+    }
+
+    /// Formats the duration as a WebVTT timestamp.
+    /// The output is in the format "MM:SS.xxx" while the hours are zero and
+    /// "HH:MM:SS.xxx" otherwise, with the milliseconds truncated.
+    fn fmt_webvtt(&self) -> String {
+        self.display_webvtt().to_string()
+    }
+
+    /// Formats the duration as an ASS/SSA timestamp.
+    /// The output is in the format "H:MM:SS.cc" or "-H:MM:SS.cc", rounded to
+    /// the nearest centisecond.
+    fn fmt_ass(&self) -> String {
+        self.display_ass().to_string()
+    }
+
+    /// Formats the duration as a cue sheet time.
+    /// The output is in the format "MM:SS:FF" with frames of 1/75 second,
+    /// truncated. The minutes do not wrap into hours.
+    fn fmt_cd_frames(&self) -> String {
+        self.display_cd_frames().to_string()
+    }
+
+    /// Formats the duration as an LRC timestamp.
+    /// The output is in the format "MM:SS.xx" or "-MM:SS.xx", truncated to
+    /// centiseconds. The minutes do not wrap into hours.
+    fn fmt_lrc(&self) -> String {
+        self.display_lrc().to_string()
+    }
+
+    /// Formats the duration like Go's `time.Duration.String()`.
+    /// The output is in the format "1h2m3.5s" or "-1h2m3.5s". Durations
+    /// below one second use "ms", "µs" or "ns" instead, e.g. "1.5ms", and
+    /// zero is written as "0s".
+    fn fmt_go(&self) -> String {
+        self.display_go().to_string()
+    }
+
+    /// Formats the duration like systemd's `format_timespan()`, to the
+    /// microsecond.
+    /// The output is in the format "1y 2month 3w 4d 5h 6min 7.500000s".
+    /// Zero units are omitted, seconds below a minute carry a fraction and
+    /// zero is written as "0". Negative durations get a leading "-", which
+    /// systemd does not accept.
+    fn fmt_systemd(&self) -> String {
+        self.display_systemd().to_string()
+    }
+
+    /// Formats the duration like systemd's `format_timespan()` with the given
+    /// accuracy, e.g. "1h 30min 5.500s" for an accuracy of one millisecond.
+    /// Units below the accuracy are left out once a larger one was written.
+    fn fmt_systemd_with_accuracy<A: Hhmmss + ?Sized>(&self, accuracy: &A) -> String {
+        self.display_systemd().with_accuracy(accuracy).to_string()
+    }
+
+    /// Formats the duration like PostgreSQL's `interval` output in the given
+    /// `IntervalStyle`, truncated to microseconds.
+    /// The output is in the format "1 day 01:23:45.678",
+    /// "@ 1 day 1 hour 23 mins 45.678 secs", "1 1:23:45.678" or
+    /// "P1DT1H23M45.678S". Whole days are split off the hours.
+    fn fmt_postgres(&self, style: IntervalStyle) -> String {
+        self.display_postgres(style).to_string()
+    }
+
+    /// Formats the duration in words, e.g. "1 hour 23 minutes 45 seconds" or
+    /// "1h 23m 45s", depending on the [`WordedFormat`].
+    fn fmt_worded(&self, format: &WordedFormat) -> String {
+        self.display_worded(format).to_string()
+    }
+
+    /// Formats the duration in a compiled [`DurationFormat`], e.g.
+    /// "%s%H:%M:%S.%3f" for the same output as [`Hhmmss::hhmmssxxx`].
+    fn fmt_with(&self, format: &DurationFormat) -> String {
+        self.display_with(format).to_string()
     }
 
     #[deprecated(since = "0.1.4", note = "Please use `fmt_smart` instead")]
@@ -335,46 +852,27 @@ pub trait Hhmmss: HasSubseconds {
     /// - "1.234s" for 1.234 seconds
     /// - "about 1.234s" for 1.234 seconds
     fn fmt_smart(&self) -> String {
-        let mut value = if self.part_of_milliseconds() == 0 {
-            if self.part_of_hours() == 0 {
-                if self.part_of_minutes() == 0 {
-                    if self.part_of_seconds() == 0 {
-                        "0".to_owned()
-                    } else {
-                        format!("{}s", self.part_of_seconds())
-                    }
-                } else {
-                    self.mss()
-                }
-            } else {
-                self.hmmss()
-            }
-        } else {
-            if self.part_of_hours() == 0 {
-                if self.part_of_minutes() == 0 {
-                    format!(
-                        "{}.{}s",
-                        self.part_of_seconds() as f64,
-                        self.fmt_fract(FractPartOfDuration::Milliseconds)
-                    )
-                } else {
-                    self.mss_and_fract(FractPartOfDuration::Milliseconds)
-                }
-            } else {
-                self.hmmss_and_fract(FractPartOfDuration::Milliseconds)
-            }
-        };
-
-        value.insert_str(
-            0,
-            if self.part_of_microseconds() == 0 && self.part_of_nanoseconds() == 0 {
-                ""
-            } else {
-                "about "
-            },
-        );
-
-        value
+        self.display_smart().to_string()
+    }
+    /// Returns the exact value of the duration, independent of the backend.
+    /// Unlike [`Hhmmss::is_negative`], the sign also covers durations of
+    /// less than a second, so -0.5 s stays negative.
+    fn to_duration_parts(&self) -> DurationParts {
+        DurationParts::new(
+            self.is_negative() || self.subsecs() < 0,
+            self.part_of_hours_abs() * Self::SECONDS_IN_A_HOUR
+                + self.part_of_minutes_abs() * Self::SECONDS_IN_A_MINUTE
+                + self.part_of_seconds_abs(),
+            self.unsigned_subsecs() as u32,
+        )
+    }
+    /// Rounds the duration to a multiple of `to`, e.g. a [`Precision`] or
+    /// [`RoundTo::Seconds`].
+    ///
+    /// The result implements [`Hhmmss`] itself, so every layout can be applied
+    /// to it; carries propagate into the larger units.
+    fn rounded(&self, to: impl Into<RoundTo>, mode: Rounding) -> DurationParts {
+        round::round(self.to_duration_parts(), to.into(), mode)
     }
     fn fract_of_secs_abs(&self) -> f64 {
         ((self.part_of_nanoseconds_abs() as f64 / Self::NANOSECONDS_IN_A_MICROSECOND as f64
@@ -386,19 +884,21 @@ pub trait Hhmmss: HasSubseconds {
     fn fract_of_secs(&self) -> f64 {
         self.fract_of_secs_abs() * if self.is_negative() { -1.0 } else { 1.0 }
     }
-    fn fmt_fract(&self, included: FractPartOfDuration) -> String {
-        self.fmt_fract_all()
-            .chars()
-            .take(included.decimal_places())
-            .collect()
+    fn fmt_fract(&self, included: impl Into<Precision>) -> String {
+        self.display_fract(included).to_string()
     }
     fn fmt_fract_all(&self) -> String {
-        format!("{:09}", self.unsigned_subsecs())
+        self.display_fract(FractPartOfDuration::Nanoseconds)
+            .to_string()
     }
 }
 
 #[cfg(feature = "chrono")]
 impl Hhmmss for chrono::Duration {
+    fn part_of_days(&self) -> i64 {
+        self.num_days()
+    }
+
     fn part_of_hours(&self) -> i64 {
         self.num_hours()
     }
@@ -423,6 +923,10 @@ impl Hhmmss for chrono::Duration {
         self.num_nanoseconds().unwrap_or(0) % Self::NANOSECONDS_IN_A_MICROSECOND as i64
     }
 
+    fn part_of_days_abs(&self) -> u64 {
+        self.num_days().unsigned_abs()
+    }
+
     fn part_of_hours_abs(&self) -> u64 {
         self.num_hours().unsigned_abs()
     }
@@ -453,6 +957,10 @@ impl Hhmmss for chrono::Duration {
 }
 
 impl Hhmmss for std::time::Duration {
+    fn part_of_days(&self) -> i64 {
+        (self.as_secs() / Self::SECONDS_IN_A_DAY) as i64
+    }
+
     fn part_of_hours(&self) -> i64 {
         (self.as_secs() / Self::SECONDS_IN_A_HOUR) as i64
     }
@@ -477,6 +985,10 @@ impl Hhmmss for std::time::Duration {
         (self.subsec_nanos() % Self::NANOSECONDS_IN_A_MICROSECOND as u32) as i64
     }
 
+    fn part_of_days_abs(&self) -> u64 {
+        self.as_secs() / Self::SECONDS_IN_A_DAY
+    }
+
     fn part_of_hours_abs(&self) -> u64 {
         self.as_secs() / Self::SECONDS_IN_A_HOUR
     }
@@ -504,14 +1016,14 @@ impl Hhmmss for std::time::Duration {
     fn is_negative(&self) -> bool {
         false
     }
-
-    fn get_sign(&self) -> String {
-        "".to_owned()
-    }
 }
 
 #[cfg(feature = "time")]
 impl Hhmmss for time::Duration {
+    fn part_of_days(&self) -> i64 {
+        self.whole_days()
+    }
+
     fn part_of_hours(&self) -> i64 {
         self.whole_hours()
     }
@@ -536,6 +1048,10 @@ impl Hhmmss for time::Duration {
         (self.subsec_nanoseconds() % Self::NANOSECONDS_IN_A_MICROSECOND as i32) as i64
     }
 
+    fn part_of_days_abs(&self) -> u64 {
+        self.whole_days().unsigned_abs()
+    }
+
     fn part_of_hours_abs(&self) -> u64 {
         self.whole_hours().unsigned_abs()
     }
@@ -570,6 +1086,204 @@ pub(crate) trait HasSubseconds {
     fn unsigned_subsecs(&self) -> u64;
 }
 
+impl Hhmmss for DurationParts {
+    fn part_of_days(&self) -> i64 {
+        self.part_of_days_abs() as i64 * self.signum()
+    }
+
+    fn part_of_hours(&self) -> i64 {
+        self.part_of_hours_abs() as i64 * self.signum()
+    }
+
+    fn part_of_minutes(&self) -> i64 {
+        self.part_of_minutes_abs() as i64 * self.signum()
+    }
+
+    fn part_of_seconds(&self) -> i64 {
+        self.part_of_seconds_abs() as i64 * self.signum()
+    }
+
+    fn part_of_milliseconds(&self) -> i64 {
+        self.part_of_milliseconds_abs() as i64 * self.signum()
+    }
+
+    fn part_of_microseconds(&self) -> i64 {
+        self.part_of_microseconds_abs() as i64 * self.signum()
+    }
+
+    fn part_of_nanoseconds(&self) -> i64 {
+        self.part_of_nanoseconds_abs() as i64 * self.signum()
+    }
+
+    fn part_of_days_abs(&self) -> u64 {
+        self.secs / Self::SECONDS_IN_A_DAY
+    }
+
+    fn part_of_hours_abs(&self) -> u64 {
+        self.secs / Self::SECONDS_IN_A_HOUR
+    }
+
+    fn part_of_minutes_abs(&self) -> u64 {
+        (self.secs / Self::SECONDS_IN_A_MINUTE) % Self::MINUTES_IN_AN_HOUR
+    }
+
+    fn part_of_seconds_abs(&self) -> u64 {
+        self.secs % Self::SECONDS_IN_A_MINUTE
+    }
+
+    fn part_of_milliseconds_abs(&self) -> u64 {
+        self.nanos as u64 / 1_000_000
+    }
+
+    fn part_of_microseconds_abs(&self) -> u64 {
+        (self.nanos as u64 / 1_000) % Self::MICROSECONDS_IN_A_MILLISECOND
+    }
+
+    fn part_of_nanoseconds_abs(&self) -> u64 {
+        self.nanos as u64 % Self::NANOSECONDS_IN_A_MICROSECOND
+    }
+
+    fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    fn to_duration_parts(&self) -> DurationParts {
+        *self
+    }
+}
+
+impl Hhmmss for rational::RationalTime {
+    fn part_of_days(&self) -> i64 {
+        self.parts().part_of_days()
+    }
+
+    fn part_of_hours(&self) -> i64 {
+        self.parts().part_of_hours()
+    }
+
+    fn part_of_minutes(&self) -> i64 {
+        self.parts().part_of_minutes()
+    }
+
+    fn part_of_seconds(&self) -> i64 {
+        self.parts().part_of_seconds()
+    }
+
+    fn part_of_milliseconds(&self) -> i64 {
+        self.parts().part_of_milliseconds()
+    }
+
+    fn part_of_microseconds(&self) -> i64 {
+        self.parts().part_of_microseconds()
+    }
+
+    fn part_of_nanoseconds(&self) -> i64 {
+        self.parts().part_of_nanoseconds()
+    }
+
+    fn part_of_days_abs(&self) -> u64 {
+        self.parts().part_of_days_abs()
+    }
+
+    fn part_of_hours_abs(&self) -> u64 {
+        self.parts().part_of_hours_abs()
+    }
+
+    fn part_of_minutes_abs(&self) -> u64 {
+        self.parts().part_of_minutes_abs()
+    }
+
+    fn part_of_seconds_abs(&self) -> u64 {
+        self.parts().part_of_seconds_abs()
+    }
+
+    fn part_of_milliseconds_abs(&self) -> u64 {
+        self.parts().part_of_milliseconds_abs()
+    }
+
+    fn part_of_microseconds_abs(&self) -> u64 {
+        self.parts().part_of_microseconds_abs()
+    }
+
+    fn part_of_nanoseconds_abs(&self) -> u64 {
+        self.parts().part_of_nanoseconds_abs()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.ticks < 0
+    }
+
+    fn to_duration_parts(&self) -> DurationParts {
+        self.parts()
+    }
+}
+
+impl Hhmmss for samples::SamplePosition {
+    fn part_of_days(&self) -> i64 {
+        self.parts().part_of_days()
+    }
+
+    fn part_of_hours(&self) -> i64 {
+        self.parts().part_of_hours()
+    }
+
+    fn part_of_minutes(&self) -> i64 {
+        self.parts().part_of_minutes()
+    }
+
+    fn part_of_seconds(&self) -> i64 {
+        self.parts().part_of_seconds()
+    }
+
+    fn part_of_milliseconds(&self) -> i64 {
+        self.parts().part_of_milliseconds()
+    }
+
+    fn part_of_microseconds(&self) -> i64 {
+        self.parts().part_of_microseconds()
+    }
+
+    fn part_of_nanoseconds(&self) -> i64 {
+        self.parts().part_of_nanoseconds()
+    }
+
+    fn part_of_days_abs(&self) -> u64 {
+        self.parts().part_of_days_abs()
+    }
+
+    fn part_of_hours_abs(&self) -> u64 {
+        self.parts().part_of_hours_abs()
+    }
+
+    fn part_of_minutes_abs(&self) -> u64 {
+        self.parts().part_of_minutes_abs()
+    }
+
+    fn part_of_seconds_abs(&self) -> u64 {
+        self.parts().part_of_seconds_abs()
+    }
+
+    fn part_of_milliseconds_abs(&self) -> u64 {
+        self.parts().part_of_milliseconds_abs()
+    }
+
+    fn part_of_microseconds_abs(&self) -> u64 {
+        self.parts().part_of_microseconds_abs()
+    }
+
+    fn part_of_nanoseconds_abs(&self) -> u64 {
+        self.parts().part_of_nanoseconds_abs()
+    }
+
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    fn to_duration_parts(&self) -> DurationParts {
+        self.parts()
+    }
+}
+
 #[cfg(feature = "chrono")]
 impl HasSubseconds for chrono::Duration {
     fn subsecs(&self) -> i64 {
@@ -577,7 +1291,7 @@ impl HasSubseconds for chrono::Duration {
     }
 
     fn unsigned_subsecs(&self) -> u64 {
-        self.subsecs().unsigned_abs() as u64
+        self.subsecs().unsigned_abs()
     }
 }
 
@@ -587,7 +1301,7 @@ impl HasSubseconds for std::time::Duration {
     }
 
     fn unsigned_subsecs(&self) -> u64 {
-        self.subsecs().unsigned_abs() as u64
+        self.subsecs().unsigned_abs()
     }
 }
 
@@ -598,6 +1312,36 @@ impl HasSubseconds for time::Duration {
     }
 
     fn unsigned_subsecs(&self) -> u64 {
-        self.subsecs().unsigned_abs() as u64
+        self.subsecs().unsigned_abs()
+    }
+}
+
+impl HasSubseconds for DurationParts {
+    fn subsecs(&self) -> i64 {
+        self.nanos as i64 * self.signum()
+    }
+
+    fn unsigned_subsecs(&self) -> u64 {
+        self.nanos as u64
+    }
+}
+
+impl HasSubseconds for rational::RationalTime {
+    fn subsecs(&self) -> i64 {
+        self.parts().subsecs()
+    }
+
+    fn unsigned_subsecs(&self) -> u64 {
+        self.parts().unsigned_subsecs()
+    }
+}
+
+impl HasSubseconds for samples::SamplePosition {
+    fn subsecs(&self) -> i64 {
+        self.parts().subsecs()
+    }
+
+    fn unsigned_subsecs(&self) -> u64 {
+        self.parts().unsigned_subsecs()
     }
 }
@@ -0,0 +1,283 @@
+//! Locale data for worded durations.
+//!
+//! A [`Locale`] is plain data: unit names per [`UnitStyle`] and plural
+//! form, a [`PluralRule`], the separators and the decimal separator. Pick
+//! one of the bundled locales or build your own and pass it to
+//! [`WordedFormat::locale`](crate::worded::WordedFormat::locale).
+//!
+//! English, [`EN`], is always available and is used when no locale is set.
+//! The other bundled locales are behind their own cargo features:
+//! `locale-ja`, `locale-de` and `locale-fr`.
+//!
+//! Word order is limited to [`Locale::number_first`], which puts the unit
+//! name before or after each number. Units are always written from the
+//! largest to the smallest.
+//!
+//! ```
+//! # #[cfg(feature = "locale-ja")]
+//! # {
+//! use hhmmss_rs::fracts::Precision;
+//! use hhmmss_rs::locale;
+//! use hhmmss_rs::worded::{UnitStyle, WordedFormat};
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(5025, 678_000_000);
+//! let ja = WordedFormat::new(UnitStyle::Long).locale(&locale::JA);
+//! assert_eq!(d.fmt_worded(&ja), "1時間23分45秒");
+//! let ja = ja.fract(Precision::MILLISECONDS);
+//! assert_eq!(d.fmt_worded(&ja), "1時間23分45.678秒");
+//! # }
+//! ```
+
+use crate::worded::{UnitStyle, WordUnit};
+
+/// The singular and plural name of a unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitName {
+    /// Used when the [`PluralRule`] selects [`Plural::One`].
+    pub one: &'static str,
+    /// Used otherwise.
+    pub other: &'static str,
+}
+
+impl UnitName {
+    /// A name with distinct singular and plural forms.
+    pub const fn new(one: &'static str, other: &'static str) -> Self {
+        Self { one, other }
+    }
+
+    /// A name which does not change with the count.
+    pub const fn invariant(name: &'static str) -> Self {
+        Self::new(name, name)
+    }
+}
+
+/// The names and spacing of one [`UnitStyle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyleData {
+    /// The names of the units, indexed from days to nanoseconds.
+    pub names: [UnitName; 7],
+    /// Written between the number and the unit name.
+    pub spacing: &'static str,
+    /// Written between units.
+    pub separator: &'static str,
+}
+
+/// The plural form of a unit name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Plural {
+    One,
+    Other,
+}
+
+/// How a locale picks the [`Plural`] form for a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralRule {
+    /// Names never change, as in Japanese.
+    Invariant,
+    /// Singular for exactly 1 without a fraction, as in English and German.
+    OneOnly,
+    /// Singular for 0 and 1, including any fraction, as in French.
+    ZeroAndOne,
+}
+
+impl PluralRule {
+    /// Returns the plural form for a number with the integer part `count`
+    /// and a non-zero fraction if `has_fraction` is set.
+    pub fn select(&self, count: u128, has_fraction: bool) -> Plural {
+        let one = match self {
+            PluralRule::Invariant => false,
+            PluralRule::OneOnly => count == 1 && !has_fraction,
+            PluralRule::ZeroAndOne => count < 2,
+        };
+        if one {
+            Plural::One
+        } else {
+            Plural::Other
+        }
+    }
+}
+
+/// Everything needed to write a duration in words in one language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    pub long: StyleData,
+    pub short: StyleData,
+    pub narrow: StyleData,
+    pub plural: PluralRule,
+    /// Separates the integer and the fractional digits.
+    pub decimal_separator: char,
+    /// Whether the number is written before the unit name. This is the only
+    /// ordering a locale controls; the units themselves always run from the
+    /// largest to the smallest.
+    pub number_first: bool,
+}
+
+impl Locale {
+    /// Returns the names and spacing for `style`.
+    pub fn style(&self, style: UnitStyle) -> &StyleData {
+        match style {
+            UnitStyle::Long => &self.long,
+            UnitStyle::Short => &self.short,
+            UnitStyle::Narrow => &self.narrow,
+        }
+    }
+
+    /// Returns the name of `unit` in `style` for a number with the integer
+    /// part `count`.
+    pub fn unit_name(
+        &self,
+        unit: WordUnit,
+        style: UnitStyle,
+        count: u128,
+        has_fraction: bool,
+    ) -> &'static str {
+        let name = self.style(style).names[unit as usize];
+        match self.plural.select(count, has_fraction) {
+            Plural::One => name.one,
+            Plural::Other => name.other,
+        }
+    }
+}
+
+const fn n(one: &'static str, other: &'static str) -> UnitName {
+    UnitName::new(one, other)
+}
+
+const fn i(name: &'static str) -> UnitName {
+    UnitName::invariant(name)
+}
+
+/// English: "1 hour 23 minutes 45 seconds". Used when no locale is set.
+pub const EN: Locale = Locale {
+    long: StyleData {
+        names: [
+            n("day", "days"),
+            n("hour", "hours"),
+            n("minute", "minutes"),
+            n("second", "seconds"),
+            n("millisecond", "milliseconds"),
+            n("microsecond", "microseconds"),
+            n("nanosecond", "nanoseconds"),
+        ],
+        spacing: " ",
+        separator: " ",
+    },
+    short: StyleData {
+        names: [
+            n("day", "days"),
+            i("hr"),
+            i("min"),
+            i("sec"),
+            i("ms"),
+            i("μs"),
+            i("ns"),
+        ],
+        spacing: " ",
+        separator: " ",
+    },
+    narrow: StyleData {
+        names: [i("d"), i("h"), i("m"), i("s"), i("ms"), i("μs"), i("ns")],
+        spacing: "",
+        separator: " ",
+    },
+    plural: PluralRule::OneOnly,
+    decimal_separator: '.',
+    number_first: true,
+};
+
+#[cfg(feature = "locale-ja")]
+const JAPANESE: StyleData = StyleData {
+    names: [
+        i("日"),
+        i("時間"),
+        i("分"),
+        i("秒"),
+        i("ミリ秒"),
+        i("マイクロ秒"),
+        i("ナノ秒"),
+    ],
+    spacing: "",
+    separator: "",
+};
+
+/// Japanese: "1時間23分45秒". All styles are written the same way.
+#[cfg(feature = "locale-ja")]
+pub const JA: Locale = Locale {
+    long: JAPANESE,
+    short: JAPANESE,
+    narrow: JAPANESE,
+    plural: PluralRule::Invariant,
+    decimal_separator: '.',
+    number_first: true,
+};
+
+/// German: "1 Stunde 23 Minuten 45 Sekunden".
+#[cfg(feature = "locale-de")]
+pub const DE: Locale = Locale {
+    long: StyleData {
+        names: [
+            n("Tag", "Tage"),
+            n("Stunde", "Stunden"),
+            n("Minute", "Minuten"),
+            n("Sekunde", "Sekunden"),
+            n("Millisekunde", "Millisekunden"),
+            n("Mikrosekunde", "Mikrosekunden"),
+            n("Nanosekunde", "Nanosekunden"),
+        ],
+        spacing: " ",
+        separator: " ",
+    },
+    short: StyleData {
+        names: [
+            i("Tg."),
+            i("Std."),
+            i("Min."),
+            i("Sek."),
+            i("ms"),
+            i("μs"),
+            i("ns"),
+        ],
+        spacing: " ",
+        separator: " ",
+    },
+    narrow: StyleData {
+        names: [i("T"), i("h"), i("min"), i("s"), i("ms"), i("μs"), i("ns")],
+        spacing: "",
+        separator: " ",
+    },
+    plural: PluralRule::OneOnly,
+    decimal_separator: ',',
+    number_first: true,
+};
+
+/// French: "1 heure 23 minutes 45 secondes".
+#[cfg(feature = "locale-fr")]
+pub const FR: Locale = Locale {
+    long: StyleData {
+        names: [
+            n("jour", "jours"),
+            n("heure", "heures"),
+            n("minute", "minutes"),
+            n("seconde", "secondes"),
+            n("milliseconde", "millisecondes"),
+            n("microseconde", "microsecondes"),
+            n("nanoseconde", "nanosecondes"),
+        ],
+        spacing: " ",
+        separator: " ",
+    },
+    short: StyleData {
+        names: [i("j"), i("h"), i("min"), i("s"), i("ms"), i("μs"), i("ns")],
+        spacing: " ",
+        separator: " ",
+    },
+    narrow: StyleData {
+        names: [i("j"), i("h"), i("min"), i("s"), i("ms"), i("μs"), i("ns")],
+        spacing: "",
+        separator: " ",
+    },
+    plural: PluralRule::ZeroAndOne,
+    decimal_separator: ',',
+    number_first: true,
+};
@@ -0,0 +1,94 @@
+use crate::fracts::Precision;
+use crate::worded::{UnitStyle, WordUnit, WordedFormat};
+use crate::Hhmmss;
+
+#[test]
+fn test_worded_fract() {
+    let d = std::time::Duration::new(5025, 678_000_000);
+    let long = WordedFormat::new(UnitStyle::Long).fract(Precision::MILLISECONDS);
+    assert_eq!(d.fmt_worded(&long), "1 hour 23 minutes 45.678 seconds");
+    let d = std::time::Duration::new(1, 500_000_000);
+    assert_eq!(d.fmt_worded(&long), "1.5 seconds");
+    let d = std::time::Duration::new(0, 250_000_000);
+    assert_eq!(d.fmt_worded(&long), "0.25 seconds");
+    let d = std::time::Duration::new(3600, 0);
+    assert_eq!(d.fmt_worded(&long), "1 hour");
+    let minutes = WordedFormat::new(UnitStyle::Narrow)
+        .smallest(WordUnit::Minutes)
+        .fract(Precision::DECISECONDS);
+    let d = std::time::Duration::new(3600 + 90, 0);
+    assert_eq!(d.fmt_worded(&minutes), "1h 1.5m");
+}
+
+#[test]
+fn test_locale_en() {
+    let d = std::time::Duration::new(86_400 + 60, 0);
+    let en = WordedFormat::new(UnitStyle::Long).locale(&crate::locale::EN);
+    assert_eq!(d.fmt_worded(&en), "1 day 1 minute");
+}
+
+#[test]
+#[cfg(feature = "locale-ja")]
+fn test_locale_ja() {
+    let d = std::time::Duration::new(5025, 678_000_000);
+    for style in [UnitStyle::Long, UnitStyle::Short, UnitStyle::Narrow] {
+        let ja = WordedFormat::new(style).locale(&crate::locale::JA);
+        assert_eq!(d.fmt_worded(&ja), "1時間23分45秒");
+    }
+    let ja = WordedFormat::new(UnitStyle::Long)
+        .locale(&crate::locale::JA)
+        .fract(Precision::MILLISECONDS);
+    assert_eq!(d.fmt_worded(&ja), "1時間23分45.678秒");
+    let d = std::time::Duration::new(2 * 86_400, 1_500_000);
+    let ja = WordedFormat::new(UnitStyle::Long)
+        .locale(&crate::locale::JA)
+        .smallest(WordUnit::Milliseconds);
+    assert_eq!(d.fmt_worded(&ja), "2日1ミリ秒");
+    assert_eq!(std::time::Duration::new(0, 0).fmt_worded(&ja), "0ミリ秒");
+}
+
+#[test]
+#[cfg(feature = "locale-de")]
+fn test_locale_de() {
+    let d = std::time::Duration::new(5025, 0);
+    let de = WordedFormat::new(UnitStyle::Long).locale(&crate::locale::DE);
+    assert_eq!(d.fmt_worded(&de), "1 Stunde 23 Minuten 45 Sekunden");
+    let short = WordedFormat::new(UnitStyle::Short).locale(&crate::locale::DE);
+    assert_eq!(d.fmt_worded(&short), "1 Std. 23 Min. 45 Sek.");
+    let d = std::time::Duration::new(1, 500_000_000);
+    let de = de.fract(Precision::MILLISECONDS);
+    assert_eq!(d.fmt_worded(&de), "1,5 Sekunden");
+}
+
+#[test]
+#[cfg(feature = "locale-fr")]
+fn test_locale_fr() {
+    let d = std::time::Duration::new(86_400 + 7200 + 1, 0);
+    let fr = WordedFormat::new(UnitStyle::Long).locale(&crate::locale::FR);
+    assert_eq!(d.fmt_worded(&fr), "1 jour 2 heures 1 seconde");
+    let fr = fr.fract(Precision::MILLISECONDS);
+    let d = std::time::Duration::new(1, 500_000_000);
+    assert_eq!(d.fmt_worded(&fr), "1,5 seconde");
+    assert_eq!(std::time::Duration::new(0, 0).fmt_worded(&fr), "0 seconde");
+    let narrow = WordedFormat::new(UnitStyle::Narrow).locale(&crate::locale::FR);
+    assert_eq!(
+        std::time::Duration::new(5025, 0).fmt_worded(&narrow),
+        "1h 23min 45s"
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_locale_chrono() {
+    let d = chrono::Duration::milliseconds(-1500);
+    let long = WordedFormat::new(UnitStyle::Long).fract(Precision::MILLISECONDS);
+    assert_eq!(d.fmt_worded(&long), "-1.5 seconds");
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_locale_time() {
+    let d = time::Duration::milliseconds(-500);
+    let narrow = WordedFormat::new(UnitStyle::Narrow).fract(Precision::MILLISECONDS);
+    assert_eq!(d.fmt_worded(&narrow), "0.5s");
+}
@@ -0,0 +1,351 @@
+//! LRC lyrics files and their "[mm:ss.xx]" timestamps.
+//!
+//! Every lyric line starts with one or more time tags, and the enhanced
+//! format adds word-level "<mm:ss.xx>" tags. The minutes do not wrap into
+//! hours. An `[offset:+500]` header moves all lyrics 500 ms earlier:
+//!
+//! ```
+//! use hhmmss_rs::lrc::Lrc;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let mut lrc: Lrc = "[ar:Someone]\n[offset:+500]\n[00:12.00][01:15.50]Chorus\n"
+//!     .parse()
+//!     .unwrap();
+//! lrc.apply_offset();
+//! let times: Vec<_> = lrc.timeline().iter().map(|(t, _)| t.fmt_lrc()).collect();
+//! assert_eq!(times, ["00:11.50", "01:15.00"]);
+//! assert_eq!(lrc.to_string(), "[ar:Someone]\n[00:11.50][01:15.00]Chorus\n");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::fracts::Precision;
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+/// Parses an LRC timestamp such as "01:23.45" into [`DurationParts`].
+///
+/// The minutes take one or more digits and the seconds exactly two. The
+/// fraction is optional and takes two or three digits. Signs are not
+/// accepted.
+pub fn parse_timestamp(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let (_, minutes) = cursor.integer(Field::Minutes)?;
+    cursor.expect(':')?;
+    let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+    check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+    let mut nanos = 0;
+    if cursor.eat('.') {
+        let fract_pos = cursor.pos;
+        let fract = cursor.digits();
+        nanos = match fract.len() {
+            2 => fract.parse::<u32>().unwrap() * 10_000_000,
+            3 => fract.parse::<u32>().unwrap() * 1_000_000,
+            0 => return Err(cursor.unexpected()),
+            width => {
+                return Err(ParseError::InvalidWidth {
+                    position: fract_pos,
+                    field: Field::Fraction,
+                    width,
+                })
+            }
+        };
+    }
+    cursor.finish()?;
+    let secs = minutes
+        .checked_mul(60)
+        .and_then(|s| s.checked_add(seconds))
+        .ok_or(ParseError::Overflow)?;
+    Ok(DurationParts::new(false, secs, nanos))
+}
+
+/// Writes a time tag body, with milliseconds only where centiseconds would
+/// lose precision.
+struct Tag(DurationParts);
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.nanos.is_multiple_of(10_000_000) {
+            self.0.display_lrc().fmt(f)
+        } else {
+            self.0
+                .display_total_mmss_and_fract(Precision::MILLISECONDS)
+                .fmt(f)
+        }
+    }
+}
+
+/// A piece of an enhanced lyric line, starting at its word-level tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Word {
+    /// The time of the "<mm:ss.xx>" tag before the text. `None` for text
+    /// before the first tag and for lines without word-level tags.
+    pub time: Option<DurationParts>,
+    pub text: String,
+}
+
+/// A lyric line and the times it is shown at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lyric {
+    /// The times of all "[mm:ss.xx]" tags at the start of the line.
+    pub times: Vec<DurationParts>,
+    /// The text, split at word-level tags. Empty for blank lyric lines.
+    pub words: Vec<Word>,
+}
+
+impl Lyric {
+    /// Returns the text without word-level tags.
+    pub fn text(&self) -> String {
+        self.words.iter().map(|word| word.text.as_str()).collect()
+    }
+}
+
+/// A line of an LRC file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    /// An ID tag such as `[ar:Artist]` or `[offset:+500]`, kept verbatim.
+    Tag {
+        key: String,
+        value: String,
+    },
+    Lyric(Lyric),
+    /// Any other line, such as a blank line, kept verbatim.
+    Other(String),
+}
+
+/// An LRC file.
+///
+/// Parse it with [`str::parse`] and write it back with its [`Display`]
+/// implementation. Time tags are written as "[mm:ss.xx]", or "[mm:ss.xxx]"
+/// where needed, and line endings as "\n".
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lrc {
+    pub lines: Vec<Line>,
+}
+
+/// What went wrong in an [`LrcError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LrcErrorKind {
+    /// A time tag could not be parsed. Positions are byte offsets into the
+    /// line.
+    Timestamp(ParseError),
+    /// A "[" is not closed.
+    UnterminatedTag,
+    /// The `offset` tag is not a whole number of milliseconds.
+    InvalidOffset,
+}
+
+/// An error pointing at the offending line of an [`Lrc`] file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LrcError {
+    /// The line the error was found on, starting at 1.
+    pub line: usize,
+    pub kind: LrcErrorKind,
+}
+
+impl fmt::Display for LrcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            LrcErrorKind::Timestamp(error) => write!(f, "invalid time tag: {}", error),
+            LrcErrorKind::UnterminatedTag => f.write_str("expected \"]\""),
+            LrcErrorKind::InvalidOffset => f.write_str("invalid offset"),
+        }
+    }
+}
+
+impl std::error::Error for LrcError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            LrcErrorKind::Timestamp(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+fn parse_offset(value: &str) -> Option<i64> {
+    value.trim().parse().ok()
+}
+
+/// Splits lyric text at word-level tags. A "<" only starts a tag if a
+/// valid timestamp and ">" follow it; anything else is kept as text.
+fn parse_words(mut text: &str) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut current = Word {
+        time: None,
+        text: String::new(),
+    };
+    while !text.is_empty() {
+        let tag = text.strip_prefix('<').and_then(|rest| {
+            let end = rest.find('>')?;
+            Some((parse_timestamp(&rest[..end]).ok()?, &rest[end + 1..]))
+        });
+        if let Some((time, rest)) = tag {
+            if current.time.is_some() || !current.text.is_empty() {
+                words.push(current);
+            }
+            current = Word {
+                time: Some(time),
+                text: String::new(),
+            };
+            text = rest;
+        } else {
+            let first = text.chars().next().map_or(0, char::len_utf8);
+            let len = text[first..].find('<').map_or(text.len(), |i| i + first);
+            current.text.push_str(&text[..len]);
+            text = &text[len..];
+        }
+    }
+    if current.time.is_some() || !current.text.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn parse_line(line: &str) -> Result<Line, LrcErrorKind> {
+    let Some(body) = line.strip_prefix('[') else {
+        return Ok(Line::Other(line.to_owned()));
+    };
+    if !body.starts_with(|c: char| c.is_ascii_digit()) {
+        let end = body.find(']').ok_or(LrcErrorKind::UnterminatedTag)?;
+        return Ok(match body[..end].split_once(':') {
+            Some((key, value)) if body[end + 1..].trim().is_empty() => {
+                if key.trim() == "offset" && parse_offset(value).is_none() {
+                    return Err(LrcErrorKind::InvalidOffset);
+                }
+                Line::Tag {
+                    key: key.to_owned(),
+                    value: value.to_owned(),
+                }
+            }
+            _ => Line::Other(line.to_owned()),
+        });
+    }
+    let mut times = Vec::new();
+    let mut pos = 0;
+    while line[pos..].starts_with('[') {
+        let start = pos + 1;
+        let end = line[start..]
+            .find(']')
+            .ok_or(LrcErrorKind::UnterminatedTag)?
+            + start;
+        let time = parse_timestamp(&line[start..end])
+            .map_err(|error| LrcErrorKind::Timestamp(error.map_position(|p| p + start)))?;
+        times.push(time);
+        pos = end + 1;
+    }
+    Ok(Line::Lyric(Lyric {
+        times,
+        words: parse_words(&line[pos..]),
+    }))
+}
+
+impl FromStr for Lrc {
+    type Err = LrcError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+        let lines = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_line(line).map_err(|kind| LrcError { line: i + 1, kind }))
+            .collect::<Result<_, _>>()?;
+        Ok(Lrc { lines })
+    }
+}
+
+impl fmt::Display for Lrc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            match line {
+                Line::Tag { key, value } => write!(f, "[{}:{}]", key, value)?,
+                Line::Lyric(lyric) => {
+                    for time in &lyric.times {
+                        write!(f, "[{}]", Tag(*time))?;
+                    }
+                    for word in &lyric.words {
+                        if let Some(time) = word.time {
+                            write!(f, "<{}>", Tag(time))?;
+                        }
+                        f.write_str(&word.text)?;
+                    }
+                }
+                Line::Other(text) => f.write_str(text)?,
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Lrc {
+    /// Returns the trimmed value of the first ID tag named `key`.
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.lines.iter().find_map(|line| match line {
+            Line::Tag { key: k, value } if k.trim() == key => Some(value.trim()),
+            _ => None,
+        })
+    }
+
+    /// Returns the `offset` tag in milliseconds. Positive offsets show the
+    /// lyrics earlier.
+    pub fn offset(&self) -> Option<i64> {
+        self.tag("offset").and_then(parse_offset)
+    }
+
+    /// Moves every time tag by the `offset` tag and removes it, so that the
+    /// times can be used as they are.
+    ///
+    /// Times that would fall before zero become zero.
+    pub fn apply_offset(&mut self) {
+        let Some(offset) = self.offset() else {
+            return;
+        };
+        let offset = offset as i128 * 1_000_000;
+        let apply = |time: &mut DurationParts| {
+            let nanos = (time.to_nanos() - offset).max(0);
+            *time = DurationParts::from_nanos(nanos).unwrap_or(DurationParts::new(
+                false,
+                u64::MAX,
+                999_999_999,
+            ));
+        };
+        self.lines
+            .retain(|line| !matches!(line, Line::Tag { key, .. } if key.trim() == "offset"));
+        for line in &mut self.lines {
+            if let Line::Lyric(lyric) = line {
+                lyric.times.iter_mut().for_each(apply);
+                lyric
+                    .words
+                    .iter_mut()
+                    .filter_map(|word| word.time.as_mut())
+                    .for_each(apply);
+            }
+        }
+    }
+
+    /// Returns every time tag with its lyric line, sorted by time. A line
+    /// with several time tags appears once per tag.
+    ///
+    /// The `offset` tag is not applied; see [`Lrc::apply_offset`].
+    pub fn timeline(&self) -> Vec<(DurationParts, &Lyric)> {
+        let mut timeline: Vec<_> = self
+            .lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Lyric(lyric) => Some(lyric),
+                _ => None,
+            })
+            .flat_map(|lyric| lyric.times.iter().map(move |time| (*time, lyric)))
+            .collect();
+        timeline.sort_by_key(|(time, _)| time.to_nanos());
+        timeline
+    }
+}
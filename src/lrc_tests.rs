@@ -0,0 +1,180 @@
+use crate::lrc::{parse_timestamp, Line, Lrc, LrcError, LrcErrorKind, Lyric, Word};
+use crate::parse::{DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+const SAMPLE: &str = "\u{feff}[ti: Song]\r
+[ar:Someone]\r
+[offset:-250]\r
+\r
+[00:12.00][01:15.50]Chorus line\r
+[00:20.123]Precise\r
+[100:00.00]\r
+[00:30.00]<00:30.00>Word <00:30.50>by <00:31.00>word\r
+[00:40.00]I <3 you <00:41.00>たくさん\r
+";
+
+fn d(secs: u64, nanos: u32) -> DurationParts {
+    DurationParts::new(false, secs, nanos)
+}
+
+#[test]
+fn test_fmt_lrc() {
+    let d = std::time::Duration::new(5025, 678_000_000);
+    assert_eq!(d.fmt_lrc(), "83:45.67");
+    assert_eq!(std::time::Duration::ZERO.fmt_lrc(), "00:00.00");
+    assert_eq!(
+        DurationParts::new(true, 75, 500_000_000).fmt_lrc(),
+        "-01:15.50"
+    );
+}
+
+#[test]
+fn test_parse_lrc_timestamp() {
+    assert_eq!(parse_timestamp("01:23.45"), Ok(d(83, 450_000_000)));
+    assert_eq!(parse_timestamp("1:23.456"), Ok(d(83, 456_000_000)));
+    assert_eq!(parse_timestamp("120:00"), Ok(d(7200, 0)));
+    assert_eq!(
+        parse_timestamp("01:23.4"),
+        Err(ParseError::InvalidWidth {
+            position: 6,
+            field: Field::Fraction,
+            width: 1,
+        })
+    );
+    assert_eq!(
+        parse_timestamp("01:60.00"),
+        Err(ParseError::OutOfRange {
+            position: 3,
+            field: Field::Seconds,
+            value: 60,
+        })
+    );
+    assert_eq!(
+        parse_timestamp("01:23."),
+        Err(ParseError::UnexpectedEnd { position: 6 })
+    );
+    assert_eq!(parse_timestamp(""), Err(ParseError::Empty));
+}
+
+#[test]
+fn test_parse_lrc() {
+    let lrc: Lrc = SAMPLE.parse().unwrap();
+    assert_eq!(lrc.lines.len(), 9);
+    assert_eq!(lrc.tag("ti"), Some("Song"));
+    assert_eq!(lrc.tag("ar"), Some("Someone"));
+    assert_eq!(lrc.tag("al"), None);
+    assert_eq!(lrc.offset(), Some(-250));
+    assert_eq!(lrc.lines[3], Line::Other(String::new()));
+    assert_eq!(
+        lrc.lines[4],
+        Line::Lyric(Lyric {
+            times: vec![d(12, 0), d(75, 500_000_000)],
+            words: vec![Word {
+                time: None,
+                text: "Chorus line".to_owned(),
+            }],
+        })
+    );
+    let Line::Lyric(blank) = &lrc.lines[6] else {
+        panic!("expected a lyric line");
+    };
+    assert_eq!(blank.times, [d(6000, 0)]);
+    assert!(blank.words.is_empty());
+
+    let Line::Lyric(enhanced) = &lrc.lines[7] else {
+        panic!("expected a lyric line");
+    };
+    assert_eq!(enhanced.words.len(), 3);
+    assert_eq!(enhanced.words[1].time, Some(d(30, 500_000_000)));
+    assert_eq!(enhanced.words[1].text, "by ");
+    assert_eq!(enhanced.text(), "Word by word");
+
+    // "<3 you " is not a tag and stays text.
+    let Line::Lyric(hearts) = &lrc.lines[8] else {
+        panic!("expected a lyric line");
+    };
+    assert_eq!(hearts.words[0].text, "I <3 you ");
+    assert_eq!(hearts.words[1].text, "たくさん");
+}
+
+#[test]
+fn test_write_lrc() {
+    let lrc: Lrc = SAMPLE.parse().unwrap();
+    let written = lrc.to_string();
+    assert_eq!(
+        written,
+        "[ti: Song]
+[ar:Someone]
+[offset:-250]
+
+[00:12.00][01:15.50]Chorus line
+[00:20.123]Precise
+[100:00.00]
+[00:30.00]<00:30.00>Word <00:30.50>by <00:31.00>word
+[00:40.00]I <3 you <00:41.00>たくさん
+"
+    );
+    assert_eq!(written.parse::<Lrc>(), Ok(lrc));
+}
+
+#[test]
+fn test_lrc_offset() {
+    let mut lrc: Lrc = SAMPLE.parse().unwrap();
+    lrc.apply_offset();
+    assert_eq!(lrc.offset(), None);
+    assert_eq!(lrc.lines.len(), 8);
+    let times: Vec<_> = lrc
+        .timeline()
+        .iter()
+        .map(|(time, lyric)| (time.fmt_lrc(), lyric.text()))
+        .collect();
+    assert_eq!(
+        times,
+        [
+            ("00:12.25".to_owned(), "Chorus line".to_owned()),
+            ("00:20.37".to_owned(), "Precise".to_owned()),
+            ("00:30.25".to_owned(), "Word by word".to_owned()),
+            ("00:40.25".to_owned(), "I <3 you たくさん".to_owned()),
+            ("01:15.75".to_owned(), "Chorus line".to_owned()),
+            ("100:00.25".to_owned(), String::new()),
+        ]
+    );
+    let Line::Lyric(enhanced) = &lrc.lines[6] else {
+        panic!("expected a lyric line");
+    };
+    assert_eq!(enhanced.words[2].time, Some(d(31, 250_000_000)));
+
+    // Times before zero are clamped.
+    let mut lrc: Lrc = "[offset:+1500]\n[00:01.00]a\n".parse().unwrap();
+    lrc.apply_offset();
+    assert_eq!(lrc.to_string(), "[00:00.00]a\n");
+}
+
+#[test]
+fn test_lrc_errors() {
+    let error = |s: &str| s.parse::<Lrc>().unwrap_err();
+    assert_eq!(
+        error("[ar:x]\n[00:12.00\n"),
+        LrcError {
+            line: 2,
+            kind: LrcErrorKind::UnterminatedTag,
+        }
+    );
+    assert_eq!(
+        error("[offset:soon]\n").kind,
+        LrcErrorKind::InvalidOffset
+    );
+    let e = error("[00:12.00][00:61.00]x\n");
+    assert_eq!(
+        e.kind,
+        LrcErrorKind::Timestamp(ParseError::OutOfRange {
+            position: 14,
+            field: Field::Seconds,
+            value: 61,
+        })
+    );
+    assert_eq!(
+        e.to_string(),
+        "line 1: invalid time tag: seconds at 14 is out of range: 61"
+    );
+}
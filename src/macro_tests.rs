@@ -0,0 +1,26 @@
+#![allow(clippy::identity_op)]
+
+use crate::{format_duration, Hhmmss};
+
+#[test]
+fn test_format_duration_std() {
+    let d = std::time::Duration::new((1 * 60 + 23) * 60 + 45, 678_901_234);
+    assert_eq!(format_duration!("{hh}:{mm}:{ss}", d), d.hhmmss());
+    assert_eq!(format_duration!("{h}:{mm}:{ss}.{f3}", d), d.hmmssxxx());
+    assert_eq!(format_duration!("{m}:{ss}.{f}", &d), "23:45.678901234");
+    assert_eq!(format_duration!("{{{s}}} {+}", d,), "{45} +");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_format_duration_matches_runtime_format() {
+    use crate::format::DurationFormat;
+
+    let d = chrono::Duration::seconds(-((1 * 60 + 23) * 60 + 45))
+        + chrono::Duration::nanoseconds(-678_901_234);
+    let format = DurationFormat::parse("%s%H:%M:%S.%6f").unwrap();
+    assert_eq!(
+        format_duration!("{-}{hh}:{mm}:{ss}.{f6}", d),
+        d.fmt_with(&format)
+    );
+}
@@ -0,0 +1,542 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The component of a duration string an error refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Field {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Fraction,
+    Frames,
+    Samples,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Field::Years => "years",
+            Field::Months => "months",
+            Field::Weeks => "weeks",
+            Field::Days => "days",
+            Field::Hours => "hours",
+            Field::Minutes => "minutes",
+            Field::Seconds => "seconds",
+            Field::Milliseconds => "milliseconds",
+            Field::Fraction => "fractional seconds",
+            Field::Frames => "frames",
+            Field::Samples => "samples",
+        })
+    }
+}
+
+/// An error returned when a duration string cannot be parsed.
+///
+/// Every `position` is a byte offset into the input string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+    /// A character that does not fit the layout was found.
+    UnexpectedChar { position: usize, found: char },
+    /// The input ended where more characters were expected.
+    UnexpectedEnd { position: usize },
+    /// A component did not have the required number of digits, e.g. the
+    /// minutes in "1:2:03".
+    InvalidWidth {
+        position: usize,
+        field: Field,
+        width: usize,
+    },
+    /// A component was outside its allowed range, e.g. 60 minutes.
+    OutOfRange {
+        position: usize,
+        field: Field,
+        value: u64,
+    },
+    /// More than nine fractional digits were given. `position` points at the
+    /// first superfluous digit.
+    TooManyFractionalDigits { position: usize },
+    /// A unit without a fixed length, such as ISO 8601 years or months, was
+    /// given a non-zero value.
+    UnsupportedUnit { position: usize, unit: String },
+    /// A unit appeared after a smaller unit or more than once.
+    UnitOutOfOrder { position: usize, unit: String },
+    /// The value does not fit into the target duration type.
+    Overflow,
+    /// A negative value was parsed for a type that cannot be negative.
+    Negative,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty duration string"),
+            ParseError::UnexpectedChar { position, found } => {
+                write!(f, "unexpected character {:?} at {}", found, position)
+            }
+            ParseError::UnexpectedEnd { position } => {
+                write!(f, "unexpected end of input at {}", position)
+            }
+            ParseError::InvalidWidth {
+                position,
+                field,
+                width,
+            } => write!(f, "{} at {} has {} digits", field, position, width),
+            ParseError::OutOfRange {
+                position,
+                field,
+                value,
+            } => write!(f, "{} at {} is out of range: {}", field, position, value),
+            ParseError::TooManyFractionalDigits { position } => {
+                write!(f, "more than 9 fractional digits at {}", position)
+            }
+            ParseError::UnsupportedUnit { position, unit } => {
+                write!(f, "unit {:?} at {} has no fixed length", unit, position)
+            }
+            ParseError::UnitOutOfOrder { position, unit } => {
+                write!(f, "unit {:?} at {} is out of order", unit, position)
+            }
+            ParseError::Overflow => write!(f, "duration is too large"),
+            ParseError::Negative => write!(f, "duration cannot be negative"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Translates the position, for parsers which run on a rewritten copy of
+    /// the input.
+    pub(crate) fn map_position(self, map: impl Fn(usize) -> usize) -> Self {
+        match self {
+            ParseError::UnexpectedChar { position, found } => ParseError::UnexpectedChar {
+                position: map(position),
+                found,
+            },
+            ParseError::UnexpectedEnd { position } => ParseError::UnexpectedEnd {
+                position: map(position),
+            },
+            ParseError::InvalidWidth {
+                position,
+                field,
+                width,
+            } => ParseError::InvalidWidth {
+                position: map(position),
+                field,
+                width,
+            },
+            ParseError::OutOfRange {
+                position,
+                field,
+                value,
+            } => ParseError::OutOfRange {
+                position: map(position),
+                field,
+                value,
+            },
+            ParseError::TooManyFractionalDigits { position } => {
+                ParseError::TooManyFractionalDigits {
+                    position: map(position),
+                }
+            }
+            ParseError::UnsupportedUnit { position, unit } => ParseError::UnsupportedUnit {
+                position: map(position),
+                unit,
+            },
+            ParseError::UnitOutOfOrder { position, unit } => ParseError::UnitOutOfOrder {
+                position: map(position),
+                unit,
+            },
+            error @ (ParseError::Empty | ParseError::Overflow | ParseError::Negative) => error,
+        }
+    }
+}
+
+/// A parsed duration, independent of any duration backend.
+///
+/// `secs` and `nanos` hold the absolute value; `nanos` is always below one
+/// second.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DurationParts {
+    pub negative: bool,
+    pub secs: u64,
+    pub nanos: u32,
+}
+
+impl DurationParts {
+    pub fn new(negative: bool, secs: u64, nanos: u32) -> Self {
+        Self {
+            negative: negative && (secs != 0 || nanos != 0),
+            secs,
+            nanos,
+        }
+    }
+
+    /// Returns `true` if the duration is zero.
+    pub fn is_zero(&self) -> bool {
+        self.secs == 0 && self.nanos == 0
+    }
+
+    /// Creates the parts from a signed number of nanoseconds.
+    pub fn from_nanos(nanos: i128) -> Result<Self, ParseError> {
+        let abs = nanos.unsigned_abs();
+        let secs = u64::try_from(abs / 1_000_000_000).map_err(|_| ParseError::Overflow)?;
+        Ok(Self::new(nanos < 0, secs, (abs % 1_000_000_000) as u32))
+    }
+
+    /// Returns the duration as a signed number of nanoseconds.
+    pub fn to_nanos(&self) -> i128 {
+        let abs = self.secs as i128 * 1_000_000_000 + self.nanos as i128;
+        if self.negative {
+            -abs
+        } else {
+            abs
+        }
+    }
+
+    /// Returns -1 for negative durations and 1 otherwise.
+    pub(crate) fn signum(&self) -> i64 {
+        if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+}
+
+impl FromStr for DurationParts {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+/// Conversion from [`DurationParts`] into a concrete duration type.
+///
+/// Implemented for `std::time::Duration`, and for `chrono::Duration` and
+/// `time::Duration` behind their respective features.
+pub trait ParseHhmmss: Sized {
+    /// Converts already parsed parts into `Self`.
+    fn from_parts(parts: DurationParts) -> Result<Self, ParseError>;
+
+    /// Parses any layout emitted by [`Hhmmss`](crate::Hhmmss).
+    ///
+    /// Accepted are "HH:MM:SS", "H:MM:SS", "MM:SS" and "M:SS", each with an
+    /// optional sign and 1 to 9 fractional digits, as well as the output of
+    /// `fmt_smart` such as "0", "5s" or "about 1.234s".
+    fn parse_hhmmss(s: &str) -> Result<Self, ParseError> {
+        parse(s).and_then(Self::from_parts)
+    }
+
+    /// Parses an ISO 8601 duration such as "PT1H23M45.678S" or "-P1DT2H".
+    /// See [`iso8601::parse`](crate::iso8601::parse).
+    fn parse_iso8601(s: &str) -> Result<Self, ParseError> {
+        crate::iso8601::parse(s).and_then(Self::from_parts)
+    }
+
+    /// Parses an SRT timestamp such as "01:23:45,678".
+    /// See [`srt::parse_timestamp`](crate::srt::parse_timestamp).
+    fn parse_srt(s: &str) -> Result<Self, ParseError> {
+        crate::srt::parse_timestamp(s).and_then(Self::from_parts)
+    }
+
+    /// Parses a WebVTT timestamp such as "01:23:45.678" or "23:45.678".
+    /// See [`webvtt::parse_timestamp`](crate::webvtt::parse_timestamp).
+    fn parse_webvtt(s: &str) -> Result<Self, ParseError> {
+        crate::webvtt::parse_timestamp(s).and_then(Self::from_parts)
+    }
+
+    /// Parses an ASS/SSA timestamp such as "1:23:45.67".
+    /// See [`ass::parse_timestamp`](crate::ass::parse_timestamp).
+    fn parse_ass(s: &str) -> Result<Self, ParseError> {
+        crate::ass::parse_timestamp(s).and_then(Self::from_parts)
+    }
+
+    /// Parses a duration as typed by Japanese users, such as "１時間２３分",
+    /// "1時間半" or "０１：２３：４５".
+    /// See [`japanese::parse`](crate::japanese::parse).
+    fn parse_japanese(s: &str) -> Result<Self, ParseError> {
+        crate::japanese::parse(s).and_then(Self::from_parts)
+    }
+
+    /// Parses a Go duration string such as "1h2m3.5s" or "-250ms".
+    /// See [`go::parse`](crate::go::parse).
+    fn parse_go(s: &str) -> Result<Self, ParseError> {
+        crate::go::parse(s).and_then(Self::from_parts)
+    }
+
+    /// Parses a Prometheus duration string such as "1d2h" or "30s".
+    /// See [`go::parse_prometheus`](crate::go::parse_prometheus).
+    fn parse_prometheus(s: &str) -> Result<Self, ParseError> {
+        crate::go::parse_prometheus(s).and_then(Self::from_parts)
+    }
+
+    /// Parses a systemd time span such as "1h 30min 5s" or "2d".
+    /// See [`systemd::parse`](crate::systemd::parse).
+    fn parse_systemd(s: &str) -> Result<Self, ParseError> {
+        crate::systemd::parse(s).and_then(Self::from_parts)
+    }
+
+    /// Parses PostgreSQL `interval` output in any `IntervalStyle`, such as
+    /// "1 day 01:23:45.678" or "@ 2 hours ago".
+    /// See [`postgres::parse`](crate::postgres::parse).
+    fn parse_postgres(s: &str) -> Result<Self, ParseError> {
+        crate::postgres::parse(s).and_then(Self::from_parts)
+    }
+}
+
+impl ParseHhmmss for std::time::Duration {
+    fn from_parts(parts: DurationParts) -> Result<Self, ParseError> {
+        if parts.negative {
+            return Err(ParseError::Negative);
+        }
+        Ok(std::time::Duration::new(parts.secs, parts.nanos))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ParseHhmmss for chrono::Duration {
+    fn from_parts(parts: DurationParts) -> Result<Self, ParseError> {
+        let abs = chrono::Duration::from_std(std::time::Duration::new(parts.secs, parts.nanos))
+            .map_err(|_| ParseError::Overflow)?;
+        Ok(if parts.negative { -abs } else { abs })
+    }
+}
+
+#[cfg(feature = "time")]
+impl ParseHhmmss for time::Duration {
+    fn from_parts(parts: DurationParts) -> Result<Self, ParseError> {
+        let secs = i64::try_from(parts.secs).map_err(|_| ParseError::Overflow)?;
+        let nanos = parts.nanos as i32;
+        Ok(if parts.negative {
+            time::Duration::new(-secs, -nanos)
+        } else {
+            time::Duration::new(secs, nanos)
+        })
+    }
+}
+
+/// Parses a duration string into [`DurationParts`].
+///
+/// See [`ParseHhmmss::parse_hhmmss`] for the accepted layouts.
+pub fn parse(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    cursor.eat_str("about ");
+    let negative = cursor.sign();
+
+    let (leading_pos, leading) = cursor.integer(Field::Hours)?;
+    let parts = if cursor.eat(':') {
+        let (second_pos, second) = cursor.two_digits(Field::Minutes)?;
+        if cursor.eat(':') {
+            let (third_pos, third) = cursor.two_digits(Field::Seconds)?;
+            check_sexagesimal(second_pos, Field::Minutes, second)?;
+            check_sexagesimal(third_pos, Field::Seconds, third)?;
+            let nanos = cursor.fraction()?;
+            let secs = leading
+                .checked_mul(3600)
+                .and_then(|s| s.checked_add(second * 60 + third))
+                .ok_or(ParseError::Overflow)?;
+            DurationParts::new(negative, secs, nanos)
+        } else {
+            check_sexagesimal(second_pos, Field::Seconds, second)?;
+            let nanos = cursor.fraction()?;
+            let secs = leading
+                .checked_mul(60)
+                .and_then(|s| s.checked_add(second))
+                .ok_or(ParseError::Overflow)?;
+            DurationParts::new(negative, secs, nanos)
+        }
+    } else if cursor.is_at_end() && leading == 0 && leading_pos + 1 == cursor.pos {
+        DurationParts::new(negative, 0, 0)
+    } else {
+        let nanos = cursor.fraction()?;
+        cursor.expect('s')?;
+        DurationParts::new(negative, leading, nanos)
+    };
+    cursor.finish()?;
+    Ok(parts)
+}
+
+pub(crate) fn check_sexagesimal(
+    position: usize,
+    field: Field,
+    value: u64,
+) -> Result<(), ParseError> {
+    if value < 60 {
+        Ok(())
+    } else {
+        Err(ParseError::OutOfRange {
+            position,
+            field,
+            value,
+        })
+    }
+}
+
+/// Converts up to nine fractional digits into nanoseconds.
+pub(crate) fn fraction_to_nanos(digits: &str) -> u32 {
+    let mut nanos = 0;
+    for (i, b) in digits.bytes().take(9).enumerate() {
+        nanos += (b - b'0') as u32 * 10u32.pow(8 - i as u32);
+    }
+    nanos
+}
+
+/// A byte-position-tracking reader shared by the parsers of this crate.
+pub(crate) struct Cursor<'a> {
+    pub(crate) src: &'a str,
+    pub(crate) pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    pub(crate) fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub(crate) fn is_at_end(&self) -> bool {
+        self.pos == self.src.len()
+    }
+
+    pub(crate) fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    pub(crate) fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn eat_str(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns an error describing whatever is at the current position.
+    pub(crate) fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(found) => ParseError::UnexpectedChar {
+                position: self.pos,
+                found,
+            },
+            None => ParseError::UnexpectedEnd { position: self.pos },
+        }
+    }
+
+    pub(crate) fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    pub(crate) fn finish(&self) -> Result<(), ParseError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Consumes an optional leading sign and returns `true` for "-".
+    pub(crate) fn sign(&mut self) -> bool {
+        if self.eat('-') {
+            true
+        } else {
+            self.eat('+');
+            false
+        }
+    }
+
+    /// Consumes a run of ASCII digits, which may be empty.
+    pub(crate) fn digits(&mut self) -> &'a str {
+        let rest = self.rest();
+        let len = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Consumes one or more digits and returns their start and value.
+    pub(crate) fn integer(&mut self, field: Field) -> Result<(usize, u64), ParseError> {
+        let start = self.pos;
+        let digits = self.digits();
+        if digits.is_empty() {
+            return Err(self.unexpected());
+        }
+        let value = digits.parse().map_err(|_| ParseError::OutOfRange {
+            position: start,
+            field,
+            value: u64::MAX,
+        })?;
+        Ok((start, value))
+    }
+
+    /// Consumes exactly two digits and returns their start and value.
+    pub(crate) fn two_digits(&mut self, field: Field) -> Result<(usize, u64), ParseError> {
+        let start = self.pos;
+        let digits = self.digits();
+        match digits.len() {
+            0 => Err(self.unexpected()),
+            2 => Ok((start, digits.parse().unwrap())),
+            width => Err(ParseError::InvalidWidth {
+                position: start,
+                field,
+                width,
+            }),
+        }
+    }
+
+    /// Consumes an optional "." followed by 1 to 9 digits and returns the
+    /// value in nanoseconds.
+    pub(crate) fn fraction(&mut self) -> Result<u32, ParseError> {
+        self.fraction_after('.')
+    }
+
+    /// Like [`Cursor::fraction`], with a custom decimal separator.
+    pub(crate) fn fraction_after(&mut self, separator: char) -> Result<u32, ParseError> {
+        if !self.eat(separator) {
+            return Ok(0);
+        }
+        let start = self.pos;
+        let digits = self.digits();
+        if digits.is_empty() {
+            return Err(self.unexpected());
+        }
+        if digits.len() > 9 {
+            return Err(ParseError::TooManyFractionalDigits {
+                position: start + 9,
+            });
+        }
+        Ok(fraction_to_nanos(digits))
+    }
+}
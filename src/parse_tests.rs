@@ -0,0 +1,145 @@
+use crate::parse::{parse, DurationParts, Field, ParseError, ParseHhmmss};
+use crate::{FractPartOfDuration, Hhmmss};
+
+#[test]
+fn test_parse_layouts() {
+    let expected = DurationParts::new(false, 5025, 678_000_000);
+    assert_eq!(parse("01:23:45.678"), Ok(expected));
+    assert_eq!(parse("1:23:45.678"), Ok(expected));
+    assert_eq!(parse("+01:23:45.678"), Ok(expected));
+    assert_eq!(parse("83:45.678"), Ok(expected));
+    assert_eq!(parse("01:23:45"), Ok(DurationParts::new(false, 5025, 0)));
+    assert_eq!(parse("23:45"), Ok(DurationParts::new(false, 1425, 0)));
+    assert_eq!(parse("3:45"), Ok(DurationParts::new(false, 225, 0)));
+    assert_eq!(
+        parse("100:00:00"),
+        Ok(DurationParts::new(false, 360_000, 0))
+    );
+    assert_eq!(
+        parse("-23:45.6"),
+        Ok(DurationParts::new(true, 1425, 600_000_000))
+    );
+    assert_eq!(
+        parse("00:00:00.000000001"),
+        Ok(DurationParts::new(false, 0, 1))
+    );
+}
+
+#[test]
+fn test_parse_fmt_smart() {
+    assert_eq!(parse("0"), Ok(DurationParts::default()));
+    assert_eq!(parse("5s"), Ok(DurationParts::new(false, 5, 0)));
+    assert_eq!(
+        parse("-1.234s"),
+        Ok(DurationParts::new(true, 1, 234_000_000))
+    );
+    assert_eq!(
+        parse("about -2:01:36.789"),
+        Ok(DurationParts::new(true, 7296, 789_000_000))
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse(""), Err(ParseError::Empty));
+    assert_eq!(
+        parse("01:60:00"),
+        Err(ParseError::OutOfRange {
+            position: 3,
+            field: Field::Minutes,
+            value: 60
+        })
+    );
+    assert_eq!(
+        parse("1:2:03"),
+        Err(ParseError::InvalidWidth {
+            position: 2,
+            field: Field::Minutes,
+            width: 1
+        })
+    );
+    assert_eq!(
+        parse("01:23:45.1234567890"),
+        Err(ParseError::TooManyFractionalDigits { position: 18 })
+    );
+    assert_eq!(
+        parse("01:23:45."),
+        Err(ParseError::UnexpectedEnd { position: 9 })
+    );
+    assert_eq!(
+        parse("01:23:45x"),
+        Err(ParseError::UnexpectedChar {
+            position: 8,
+            found: 'x'
+        })
+    );
+    assert_eq!(parse("5"), Err(ParseError::UnexpectedEnd { position: 1 }));
+    assert_eq!(
+        parse("99999999999999999999:00:00"),
+        Err(ParseError::OutOfRange {
+            position: 0,
+            field: Field::Hours,
+            value: u64::MAX
+        })
+    );
+    assert_eq!(
+        parse("9999999999999999999:00:00"),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+fn test_parse_std() {
+    let d = std::time::Duration::parse_hhmmss("01:23:45.678901234").unwrap();
+    assert_eq!(d, std::time::Duration::new(5025, 678_901_234));
+    assert_eq!(
+        std::time::Duration::parse_hhmmss("-00:00:01"),
+        Err(ParseError::Negative)
+    );
+    assert_eq!(
+        std::time::Duration::parse_hhmmss("-00:00:00"),
+        Ok(std::time::Duration::ZERO)
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_parse_chrono() {
+    let d = chrono::Duration::parse_hhmmss("-1:23:45.678901234").unwrap();
+    assert_eq!(
+        d,
+        chrono::Duration::seconds(-5025) + chrono::Duration::nanoseconds(-678_901_234)
+    );
+    assert_eq!(
+        d.hhmmss_and_fract(FractPartOfDuration::Nanoseconds),
+        "-01:23:45.678901234"
+    );
+    assert_eq!(
+        chrono::Duration::parse_hhmmss("9999999999999:00:00"),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_parse_time() {
+    let d = time::Duration::parse_hhmmss("-23:45.678").unwrap();
+    assert_eq!(d, time::Duration::new(-1425, -678_000_000));
+    assert_eq!(d.mmssxxx(), "-23:45.678");
+}
+
+#[test]
+fn test_parse_round_trip() {
+    let d = std::time::Duration::new(5025, 678_901_234);
+    for s in [
+        d.hhmmss(),
+        d.hmmss(),
+        d.hhmmssxxx(),
+        d.hmmssxxx(),
+        d.hhmmss_and_fract(FractPartOfDuration::Microseconds),
+        d.fmt_smart(),
+    ] {
+        let parsed = std::time::Duration::parse_hhmmss(&s).unwrap();
+        assert_eq!(parsed.as_secs(), 5025, "{}", s);
+    }
+}
@@ -0,0 +1,330 @@
+//! PostgreSQL `interval` text in each of its `IntervalStyle` settings.
+//!
+//! Postgres keeps months, days and microseconds apart. A duration has no
+//! months, so it is written with whole days split off the hours, as Postgres
+//! writes `interval '1 day 01:23:45'` or the result of `justify_hours()`.
+//! Digits below a microsecond are truncated.
+//!
+//! ```
+//! use hhmmss_rs::parse::ParseHhmmss as _;
+//! use hhmmss_rs::postgres::IntervalStyle;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(91_425, 678_000_000);
+//! assert_eq!(d.fmt_postgres(IntervalStyle::Postgres), "1 day 01:23:45.678");
+//! assert_eq!(
+//!     d.fmt_postgres(IntervalStyle::PostgresVerbose),
+//!     "@ 1 day 1 hour 23 mins 45.678 secs"
+//! );
+//! assert_eq!(d.fmt_postgres(IntervalStyle::SqlStandard), "1 1:23:45.678");
+//! assert_eq!(d.fmt_postgres(IntervalStyle::Iso8601), "P1DT1H23M45.678S");
+//! assert_eq!(
+//!     std::time::Duration::parse_postgres("1 day 01:23:45.678"),
+//!     Ok(d)
+//! );
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::display::write_fract_trimmed;
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// The output styles of Postgres' `IntervalStyle` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IntervalStyle {
+    /// "1 day 01:23:45.678" or "-1 days -01:23:45.678", the default.
+    #[default]
+    Postgres,
+    /// "@ 1 day 1 hour 23 mins 45.678 secs" or "@ 1 day ... ago".
+    PostgresVerbose,
+    /// "1 1:23:45.678" or "-1 1:23:45.678".
+    SqlStandard,
+    /// "P1DT1H23M45.678S" or "P-1DT-1H-23M-45.678S".
+    Iso8601,
+}
+
+/// Writes a duration like Postgres' `interval` output.
+pub struct DisplayPostgres<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    pub(crate) style: IntervalStyle,
+}
+
+/// Writes the seconds followed by their fraction without trailing zeros.
+fn write_seconds(f: &mut fmt::Formatter<'_>, seconds: u64, nanos: u64, pad: bool) -> fmt::Result {
+    if pad {
+        write!(f, "{:02}", seconds)?;
+    } else {
+        write!(f, "{}", seconds)?;
+    }
+    if nanos != 0 {
+        f.write_char('.')?;
+        write_fract_trimmed(f, nanos)?;
+    }
+    Ok(())
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for DisplayPostgres<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        let days = d.part_of_days_abs();
+        let hours = d.part_of_hours_of_day_abs();
+        let minutes = d.part_of_minutes_abs();
+        let seconds = d.part_of_seconds_abs();
+        let subsecs = d.unsigned_subsecs();
+        let nanos = subsecs - subsecs % 1_000;
+        let has_time = hours != 0 || minutes != 0 || seconds != 0 || nanos != 0;
+        let is_zero = days == 0 && !has_time;
+        let minus = if d.to_duration_parts().negative && !is_zero {
+            "-"
+        } else {
+            ""
+        };
+        match self.style {
+            IntervalStyle::Postgres => {
+                if days != 0 {
+                    let plural = if minus.is_empty() && days == 1 {
+                        ""
+                    } else {
+                        "s"
+                    };
+                    write!(f, "{}{} day{}", minus, days, plural)?;
+                    if !has_time {
+                        return Ok(());
+                    }
+                    f.write_char(' ')?;
+                }
+                write!(f, "{}{:02}:{:02}:", minus, hours, minutes)?;
+                write_seconds(f, seconds, nanos, true)
+            }
+            IntervalStyle::PostgresVerbose => {
+                f.write_char('@')?;
+                for (value, unit) in [(days, "day"), (hours, "hour"), (minutes, "min")] {
+                    if value != 0 {
+                        let plural = if value == 1 { "" } else { "s" };
+                        write!(f, " {} {}{}", value, unit, plural)?;
+                    }
+                }
+                if seconds != 0 || nanos != 0 {
+                    f.write_char(' ')?;
+                    write_seconds(f, seconds, nanos, false)?;
+                    let plural = if seconds == 1 && nanos == 0 { "" } else { "s" };
+                    write!(f, " sec{}", plural)?;
+                }
+                if is_zero {
+                    f.write_str(" 0")?;
+                }
+                if !minus.is_empty() {
+                    f.write_str(" ago")?;
+                }
+                Ok(())
+            }
+            IntervalStyle::SqlStandard => {
+                if is_zero {
+                    return f.write_char('0');
+                }
+                f.write_str(minus)?;
+                if days != 0 {
+                    write!(f, "{} ", days)?;
+                }
+                write!(f, "{}:{:02}:", hours, minutes)?;
+                write_seconds(f, seconds, nanos, true)
+            }
+            IntervalStyle::Iso8601 => {
+                if is_zero {
+                    return f.write_str("PT0S");
+                }
+                f.write_char('P')?;
+                if days != 0 {
+                    write!(f, "{}{}D", minus, days)?;
+                }
+                if has_time {
+                    f.write_char('T')?;
+                }
+                if hours != 0 {
+                    write!(f, "{}{}H", minus, hours)?;
+                }
+                if minutes != 0 {
+                    write!(f, "{}{}M", minus, minutes)?;
+                }
+                if seconds != 0 || nanos != 0 {
+                    f.write_str(minus)?;
+                    write_seconds(f, seconds, nanos, false)?;
+                    f.write_char('S')?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reads a signed number with an optional fraction and returns it in
+/// billionths.
+fn number(cursor: &mut Cursor) -> Result<i128, ParseError> {
+    let negative = cursor.sign();
+    let (_, whole) = cursor.integer(Field::Seconds)?;
+    let nanos = cursor.fraction()?;
+    let value = whole as i128 * NANOS_PER_SEC + nanos as i128;
+    Ok(if negative { -value } else { value })
+}
+
+/// Reads "H:MM:SS" with an optional fraction, after the hours were read.
+fn time(cursor: &mut Cursor, hours: u64) -> Result<i128, ParseError> {
+    cursor.expect(':')?;
+    let (minutes_pos, minutes) = cursor.two_digits(Field::Minutes)?;
+    cursor.expect(':')?;
+    let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+    let nanos = cursor.fraction()?;
+    check_sexagesimal(minutes_pos, Field::Minutes, minutes)?;
+    check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+    Ok(
+        (hours as i128 * 3_600 + minutes as i128 * 60 + seconds as i128) * NANOS_PER_SEC
+            + nanos as i128,
+    )
+}
+
+/// Reads a unit name of the Postgres styles and returns its length in
+/// seconds, or `None` for the units without a fixed length.
+fn unit(cursor: &mut Cursor) -> Result<(usize, &'static str, Option<i128>), ParseError> {
+    let start = cursor.pos;
+    let word = cursor.rest();
+    let len = word.bytes().take_while(|b| b.is_ascii_alphabetic()).count();
+    let (name, secs) = match &word[..len] {
+        "year" | "years" => ("year", None),
+        "mon" | "mons" => ("mon", None),
+        "day" | "days" => ("day", Some(86_400)),
+        "hour" | "hours" => ("hour", Some(3_600)),
+        "min" | "mins" => ("min", Some(60)),
+        "sec" | "secs" => ("sec", Some(1)),
+        _ => return Err(cursor.unexpected()),
+    };
+    cursor.pos += len;
+    Ok((start, name, secs))
+}
+
+/// Reads "number unit" and returns its value in nanoseconds.
+fn component(cursor: &mut Cursor) -> Result<i128, ParseError> {
+    let value = number(cursor)?;
+    cursor.expect(' ')?;
+    match unit(cursor)? {
+        (_, _, Some(secs)) => Ok(value * secs),
+        (_, _, None) if value == 0 => Ok(0),
+        (position, unit, None) => Err(ParseError::UnsupportedUnit {
+            position,
+            unit: unit.to_owned(),
+        }),
+    }
+}
+
+fn add(total: i128, value: i128) -> Result<i128, ParseError> {
+    total.checked_add(value).ok_or(ParseError::Overflow)
+}
+
+/// Parses the "postgres_verbose" style after the "@".
+fn parse_verbose(cursor: &mut Cursor) -> Result<i128, ParseError> {
+    let mut total = 0;
+    let mut components = 0;
+    while !cursor.is_at_end() {
+        cursor.expect(' ')?;
+        if components > 0 && cursor.eat_str("ago") {
+            cursor.finish()?;
+            return Ok(-total);
+        }
+        let start = cursor.pos;
+        if components == 0 && cursor.eat('0') && cursor.rest().is_empty() {
+            return Ok(0);
+        }
+        cursor.pos = start;
+        total = add(total, component(cursor)?)?;
+        components += 1;
+    }
+    if components == 0 {
+        return Err(cursor.unexpected());
+    }
+    Ok(total)
+}
+
+/// Parses the "postgres" and "sql_standard" styles.
+fn parse_plain(cursor: &mut Cursor) -> Result<i128, ParseError> {
+    let mut total = 0;
+    let mut first = true;
+    loop {
+        let start = cursor.pos;
+        let negative = cursor.sign();
+        let (_, whole) = cursor.integer(Field::Hours)?;
+        if cursor.peek() == Some(':') {
+            // "[+-]H:MM:SS", the last part of both styles.
+            let value = time(cursor, whole)?;
+            cursor.finish()?;
+            return add(total, if negative { -value } else { value });
+        }
+        if first && cursor.eat('-') {
+            // "Y-M", the year-month form of "sql_standard".
+            let (months_pos, months) = cursor.integer(Field::Months)?;
+            cursor.finish()?;
+            let (position, unit) = match (whole, months) {
+                (0, 0) => return Ok(0),
+                (0, _) => (months_pos, "mon"),
+                _ => (start, "year"),
+            };
+            return Err(ParseError::UnsupportedUnit {
+                position,
+                unit: unit.to_owned(),
+            });
+        }
+        let rest = cursor.rest();
+        if first && rest.starts_with(' ') && rest[1..].starts_with(|c: char| c.is_ascii_digit()) {
+            // "[+-]D H:MM:SS" of "sql_standard", where the sign applies to
+            // the whole interval.
+            cursor.bump();
+            let (_, hours) = cursor.integer(Field::Hours)?;
+            let value = whole as i128 * 86_400 * NANOS_PER_SEC + time(cursor, hours)?;
+            cursor.finish()?;
+            return Ok(if negative { -value } else { value });
+        }
+        cursor.pos = start;
+        total = add(total, component(cursor)?)?;
+        if cursor.is_at_end() {
+            return Ok(total);
+        }
+        cursor.expect(' ')?;
+        first = false;
+    }
+}
+
+/// Parses Postgres `interval` output in any of the four styles into
+/// [`DurationParts`], detecting the style from the text.
+///
+/// The accepted forms are:
+///
+/// - "postgres": `[n day[s]] [+-]H:MM:SS[.f]`, e.g. "1 day 01:23:45.678",
+///   "-1 days -01:23:45" or "2 days", where every part carries its own sign,
+/// - "postgres_verbose": `@ n unit[s] ... [ago]` with the units "day",
+///   "hour", "min" and "sec", e.g. "@ 1 day 2 hours 5.5 secs ago", or "@ 0",
+/// - "sql_standard": `[+-][D ]H:MM:SS[.f]`, e.g. "-1 1:23:45", where the
+///   sign applies to the whole interval, or "0",
+/// - "iso_8601": "P1DT1H23M45.678S", parsed by [`iso8601::parse`].
+///
+/// Years and months have no fixed length and are only accepted with a value
+/// of zero. Up to nine fractional digits are accepted.
+///
+/// [`iso8601::parse`]: crate::iso8601::parse
+pub fn parse(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if s.starts_with(['P', 'p']) {
+        return crate::iso8601::parse(s);
+    }
+    let mut cursor = Cursor::new(s);
+    let total = if cursor.eat('@') {
+        parse_verbose(&mut cursor)?
+    } else if s == "0" {
+        0
+    } else {
+        parse_plain(&mut cursor)?
+    };
+    DurationParts::from_nanos(total)
+}
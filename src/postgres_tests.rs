@@ -0,0 +1,214 @@
+use crate::parse::{DurationParts, Field, ParseError, ParseHhmmss};
+use crate::postgres::{parse, IntervalStyle};
+use crate::Hhmmss;
+
+const STYLES: [IntervalStyle; 4] = [
+    IntervalStyle::Postgres,
+    IntervalStyle::PostgresVerbose,
+    IntervalStyle::SqlStandard,
+    IntervalStyle::Iso8601,
+];
+
+fn secs(negative: bool, secs: u64, nanos: u32) -> DurationParts {
+    DurationParts::new(negative, secs, nanos)
+}
+
+#[test]
+fn test_fmt_postgres() {
+    for (d, expected) in [
+        (
+            secs(false, 91_425, 678_000_000),
+            [
+                "1 day 01:23:45.678",
+                "@ 1 day 1 hour 23 mins 45.678 secs",
+                "1 1:23:45.678",
+                "P1DT1H23M45.678S",
+            ],
+        ),
+        (
+            secs(true, 91_425, 678_000_000),
+            [
+                "-1 days -01:23:45.678",
+                "@ 1 day 1 hour 23 mins 45.678 secs ago",
+                "-1 1:23:45.678",
+                "P-1DT-1H-23M-45.678S",
+            ],
+        ),
+        (secs(false, 0, 0), ["00:00:00", "@ 0", "0", "PT0S"]),
+        (
+            secs(false, 172_800, 0),
+            ["2 days", "@ 2 days", "2 0:00:00", "P2D"],
+        ),
+        (
+            secs(true, 86_400, 0),
+            ["-1 days", "@ 1 day ago", "-1 0:00:00", "P-1D"],
+        ),
+        (
+            secs(false, 1, 0),
+            ["00:00:01", "@ 1 sec", "0:00:01", "PT1S"],
+        ),
+        (
+            secs(false, 3_600, 0),
+            ["01:00:00", "@ 1 hour", "1:00:00", "PT1H"],
+        ),
+        (
+            secs(false, 360_060, 0),
+            [
+                "4 days 04:01:00",
+                "@ 4 days 4 hours 1 min",
+                "4 4:01:00",
+                "P4DT4H1M",
+            ],
+        ),
+        (
+            secs(false, 0, 1_500),
+            [
+                "00:00:00.000001",
+                "@ 0.000001 secs",
+                "0:00:00.000001",
+                "PT0.000001S",
+            ],
+        ),
+        (secs(true, 0, 500), ["00:00:00", "@ 0", "0", "PT0S"]),
+    ] {
+        for (style, expected) in STYLES.into_iter().zip(expected) {
+            assert_eq!(d.fmt_postgres(style), expected, "{:?}", style);
+        }
+    }
+    assert_eq!(IntervalStyle::default(), IntervalStyle::Postgres);
+}
+
+#[test]
+fn test_parse_postgres() {
+    for (s, expected) in [
+        ("00:00:00", secs(false, 0, 0)),
+        ("-00:30:00", secs(true, 1_800, 0)),
+        ("+01:00:00.5", secs(false, 3_600, 500_000_000)),
+        ("1 day", secs(false, 86_400, 0)),
+        ("3 days 04:05:06", secs(false, 273_906, 0)),
+        ("1 day -01:00:00", secs(false, 82_800, 0)),
+        ("0 years 0 mons 1 day", secs(false, 86_400, 0)),
+        ("@ 1 min 30 secs", secs(false, 90, 0)),
+        ("@ 5.5 secs ago", secs(true, 5, 500_000_000)),
+        ("@ 1 day -1 hours", secs(false, 82_800, 0)),
+        ("@ 0", secs(false, 0, 0)),
+        ("0", secs(false, 0, 0)),
+        ("0-0", secs(false, 0, 0)),
+        ("1 1:23:45", secs(false, 91_425, 0)),
+        ("-1 1:23:45", secs(true, 91_425, 0)),
+        ("100:00:00", secs(false, 360_000, 0)),
+        ("P-1DT-1H", secs(true, 90_000, 0)),
+    ] {
+        assert_eq!(parse(s), Ok(expected), "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_postgres_round_trip() {
+    for d in [
+        secs(false, 91_425, 678_000_000),
+        secs(true, 91_425, 678_000_000),
+        secs(false, 0, 0),
+        secs(true, 172_800, 0),
+        secs(false, 3_599, 1_000),
+        secs(true, 360_060, 0),
+    ] {
+        for style in STYLES {
+            assert_eq!(parse(&d.fmt_postgres(style)), Ok(d), "{:?}", style);
+        }
+    }
+}
+
+#[test]
+fn test_parse_postgres_errors() {
+    assert_eq!(parse(""), Err(ParseError::Empty));
+    assert_eq!(parse("@"), Err(ParseError::UnexpectedEnd { position: 1 }));
+    for (s, position, found) in [
+        ("1 fortnight", 2, 'f'),
+        ("01:00:00 1 day", 8, ' '),
+        ("1 day  01:00:00", 6, ' '),
+        ("@ ago", 2, 'a'),
+        ("@1 day", 1, '1'),
+        ("1 day x", 6, 'x'),
+    ] {
+        assert_eq!(
+            parse(s),
+            Err(ParseError::UnexpectedChar { position, found }),
+            "{}",
+            s
+        );
+    }
+    assert_eq!(
+        parse("01:60:00"),
+        Err(ParseError::OutOfRange {
+            position: 3,
+            field: Field::Minutes,
+            value: 60,
+        })
+    );
+    for (s, position, unit) in [
+        ("1-2", 0, "year"),
+        ("0-2", 2, "mon"),
+        ("1 year 2 mons", 2, "year"),
+        ("@ 3 mons ago", 4, "mon"),
+        ("P1Y", 2, "Y"),
+    ] {
+        assert_eq!(
+            parse(s),
+            Err(ParseError::UnsupportedUnit {
+                position,
+                unit: unit.to_owned(),
+            }),
+            "{}",
+            s
+        );
+    }
+    assert_eq!(
+        parse("18446744073709551615 days"),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+fn test_parse_postgres_into_durations() {
+    assert_eq!(
+        std::time::Duration::parse_postgres("1 day 01:23:45.678"),
+        Ok(std::time::Duration::new(91_425, 678_000_000))
+    );
+    assert_eq!(
+        std::time::Duration::parse_postgres("-1 days"),
+        Err(ParseError::Negative)
+    );
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        chrono::Duration::parse_postgres("-1 days -01:00:00"),
+        Ok(chrono::Duration::hours(-25))
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_fmt_postgres_chrono_under_one_second() {
+    let d = chrono::Duration::milliseconds(-700);
+    for (style, expected) in
+        STYLES
+            .into_iter()
+            .zip(["-00:00:00.7", "@ 0.7 secs ago", "-0:00:00.7", "PT-0.7S"])
+    {
+        assert_eq!(d.fmt_postgres(style), expected, "{:?}", style);
+        assert_eq!(chrono::Duration::parse_postgres(expected), Ok(d));
+    }
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_fmt_postgres_time_under_one_second() {
+    let d = time::Duration::milliseconds(-700);
+    for style in STYLES {
+        assert_eq!(
+            time::Duration::parse_postgres(&d.fmt_postgres(style)),
+            Ok(d)
+        );
+    }
+    assert_eq!(d.fmt_postgres(IntervalStyle::Postgres), "-00:00:00.7");
+}
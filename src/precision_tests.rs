@@ -0,0 +1,60 @@
+#![allow(clippy::identity_op)]
+
+use crate::fracts::Precision;
+use crate::{FractPartOfDuration, Hhmmss};
+
+#[test]
+fn test_precision_values() {
+    assert_eq!(Precision::new(0), None);
+    assert_eq!(Precision::new(10), None);
+    assert_eq!(Precision::try_from(2), Ok(Precision::CENTISECONDS));
+    assert_eq!(Precision::try_from(11), Err(11));
+    assert_eq!(
+        Precision::from(FractPartOfDuration::Microseconds),
+        Precision::MICROSECONDS
+    );
+    assert_eq!(Precision::DECISECONDS.units_per_sec(), 10);
+    assert_eq!(Precision::NANOSECONDS.units_per_sec(), 1_000_000_000);
+    for digits in 1..=9 {
+        let precision = Precision::new(digits).unwrap();
+        assert_eq!(precision.decimal_places(), digits as usize);
+    }
+}
+
+#[test]
+fn test_precision_layouts_std() {
+    let d = std::time::Duration::new((1 * 60 + 23) * 60 + 45, 678_901_234);
+    assert_eq!(d.hhmmss_and_fract(Precision::DECISECONDS), "01:23:45.6");
+    assert_eq!(d.hhmmss_and_fract(Precision::CENTISECONDS), "01:23:45.67");
+    assert_eq!(
+        d.hmmss_and_fract(Precision::new(4).unwrap()),
+        "1:23:45.6789"
+    );
+    assert_eq!(d.mss_and_fract(Precision::new(5).unwrap()), "23:45.67890");
+    assert_eq!(d.fmt_fract(Precision::new(7).unwrap()), "6789012");
+    assert_eq!(d.fmt_fract(Precision::new(8).unwrap()), "67890123");
+    assert_eq!(
+        d.hhmmss_and_fract(FractPartOfDuration::Milliseconds),
+        d.hhmmss_and_fract(Precision::MILLISECONDS)
+    );
+    assert_eq!(
+        d.display_total_mmss_and_fract(Precision::CENTISECONDS)
+            .to_string(),
+        "83:45.67"
+    );
+}
+
+#[test]
+fn test_precision_leading_zeros() {
+    let d = std::time::Duration::new(5, 1_000_000);
+    assert_eq!(d.mss_and_fract(Precision::DECISECONDS), "0:05.0");
+    assert_eq!(d.mss_and_fract(Precision::CENTISECONDS), "0:05.00");
+    assert_eq!(d.mss_and_fract(Precision::MILLISECONDS), "0:05.001");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_precision_layouts_chrono() {
+    let d = chrono::Duration::milliseconds(-(((1 * 60 + 23) * 60 + 45) * 1000 + 678));
+    assert_eq!(d.hmmss_and_fract(Precision::CENTISECONDS), "-1:23:45.67");
+}
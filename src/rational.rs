@@ -0,0 +1,116 @@
+//! Tick counts in a rational timebase, as used by media containers and
+//! decoders (presentation timestamps).
+//!
+//! [`RationalTime`] implements [`Hhmmss`] with integer math only, so every
+//! layout works on it directly:
+//!
+//! ```
+//! use hhmmss_rs::rational::{RationalTime, Timebase};
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let pts = RationalTime::new(452_261_025, Timebase::MPEG);
+//! assert_eq!(pts.hhmmssxxx(), "01:23:45.122");
+//! let frame = RationalTime::new(3, Timebase::new(1001, 30000).unwrap());
+//! assert_eq!(frame.fmt_fract_all(), "100100000");
+//! ```
+
+use crate::parse::{DurationParts, ParseError, ParseHhmmss};
+use crate::round::{div_round, Rounding};
+use crate::Hhmmss;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// The length of one tick in seconds, as the fraction `num / den`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timebase {
+    num: u32,
+    den: u32,
+}
+
+impl Timebase {
+    /// 1/90000, the clock of MPEG transport and program streams.
+    pub const MPEG: Timebase = Timebase {
+        num: 1,
+        den: 90_000,
+    };
+    /// 1/1000, as used by Matroska and FLV.
+    pub const MILLISECONDS: Timebase = Timebase { num: 1, den: 1000 };
+
+    /// Returns the timebase `num / den`, or `None` if either is zero.
+    pub const fn new(num: u32, den: u32) -> Option<Timebase> {
+        if num == 0 || den == 0 {
+            None
+        } else {
+            Some(Timebase { num, den })
+        }
+    }
+
+    /// Returns the timebase as `(numerator, denominator)`.
+    pub fn ratio(&self) -> (u32, u32) {
+        (self.num, self.den)
+    }
+}
+
+/// A signed number of ticks in a [`Timebase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RationalTime {
+    pub ticks: i64,
+    pub timebase: Timebase,
+}
+
+impl RationalTime {
+    pub fn new(ticks: i64, timebase: Timebase) -> Self {
+        Self { ticks, timebase }
+    }
+
+    /// Converts a duration into the nearest tick according to `mode`.
+    pub fn from_duration_rounded<T: Hhmmss + ?Sized>(
+        duration: &T,
+        timebase: Timebase,
+        mode: Rounding,
+    ) -> Result<Self, ParseError> {
+        let parts = duration.to_duration_parts();
+        let nanos = parts.to_nanos().unsigned_abs();
+        let ticks = div_round(
+            nanos * timebase.den as u128,
+            NANOS_PER_SEC * timebase.num as u128,
+            mode,
+            parts.negative,
+        );
+        let ticks = i64::try_from(ticks).map_err(|_| ParseError::Overflow)?;
+        Ok(Self {
+            ticks: if parts.negative { -ticks } else { ticks },
+            timebase,
+        })
+    }
+
+    /// Converts into a std, chrono or time duration, truncated to
+    /// nanoseconds.
+    pub fn to_duration<T: ParseHhmmss>(&self) -> Result<T, ParseError> {
+        let nanos = self.abs_nanos();
+        let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| ParseError::Overflow)?;
+        T::from_parts(DurationParts::new(
+            self.ticks < 0,
+            secs,
+            (nanos % NANOS_PER_SEC) as u32,
+        ))
+    }
+
+    /// Returns the magnitude in nanoseconds, truncated towards zero.
+    fn abs_nanos(&self) -> u128 {
+        self.ticks.unsigned_abs() as u128 * self.timebase.num as u128 * NANOS_PER_SEC
+            / self.timebase.den as u128
+    }
+
+    /// Returns the value truncated towards zero to whole nanoseconds.
+    ///
+    /// Magnitudes beyond `u64::MAX` seconds, only reachable with very large
+    /// timebases, saturate.
+    pub(crate) fn parts(&self) -> DurationParts {
+        let nanos = self.abs_nanos();
+        match u64::try_from(nanos / NANOS_PER_SEC) {
+            Ok(secs) => DurationParts::new(self.ticks < 0, secs, (nanos % NANOS_PER_SEC) as u32),
+            Err(_) => DurationParts::new(self.ticks < 0, u64::MAX, 999_999_999),
+        }
+    }
+}
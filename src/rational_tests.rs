@@ -0,0 +1,95 @@
+use crate::parse::{DurationParts, ParseError};
+use crate::rational::{RationalTime, Timebase};
+use crate::round::Rounding;
+use crate::Hhmmss;
+
+#[test]
+fn test_rational_layouts() {
+    let pts = RationalTime::new(452_261_025, Timebase::MPEG);
+    assert_eq!(pts.hhmmss(), "01:23:45");
+    assert_eq!(pts.hhmmssxxx(), "01:23:45.122");
+    assert_eq!(pts.fmt_fract_all(), "122500000");
+    assert_eq!(pts.part_of_minutes(), 23);
+    assert!(!pts.is_negative());
+
+    let ntsc = Timebase::new(1001, 30000).unwrap();
+    // 30 frames of 29.97 fps video last exactly 1.001 s.
+    let frames = RationalTime::new(30, ntsc);
+    assert_eq!(
+        frames.to_duration_parts(),
+        DurationParts::new(false, 1, 1_000_000)
+    );
+    // A third of a millisecond is truncated, not rounded.
+    let third = RationalTime::new(1, Timebase::new(1, 3000).unwrap());
+    assert_eq!(third.fmt_fract_all(), "000333333");
+}
+
+#[test]
+fn test_rational_negative() {
+    let pts = RationalTime::new(-135_000, Timebase::MPEG);
+    assert!(pts.is_negative());
+    assert_eq!(pts.hhmmssxxx(), "-00:00:01.500");
+    assert_eq!(pts.part_of_seconds(), -1);
+    assert_eq!(pts.part_of_milliseconds(), -500);
+    assert_eq!(pts.part_of_milliseconds_abs(), 500);
+}
+
+#[test]
+fn test_rational_timebase() {
+    assert_eq!(Timebase::new(0, 1), None);
+    assert_eq!(Timebase::new(1, 0), None);
+    assert_eq!(Timebase::MILLISECONDS.ratio(), (1, 1000));
+}
+
+#[test]
+fn test_rational_to_duration() {
+    let pts = RationalTime::new(90_001, Timebase::MPEG);
+    let d: std::time::Duration = pts.to_duration().unwrap();
+    assert_eq!(d, std::time::Duration::new(1, 11_111));
+    assert_eq!(
+        RationalTime::new(-1, Timebase::MPEG).to_duration::<std::time::Duration>(),
+        Err(ParseError::Negative)
+    );
+    let huge = RationalTime::new(i64::MAX, Timebase::new(u32::MAX, 1).unwrap());
+    assert_eq!(
+        huge.to_duration::<std::time::Duration>(),
+        Err(ParseError::Overflow)
+    );
+    assert_eq!(huge.to_duration_parts().secs, u64::MAX);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_rational_to_chrono() {
+    let pts = RationalTime::new(-3003, Timebase::new(1, 30000).unwrap());
+    let d: chrono::Duration = pts.to_duration().unwrap();
+    assert_eq!(d, chrono::Duration::microseconds(-100_100));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_rational_to_time() {
+    let pts = RationalTime::new(-3003, Timebase::new(1, 30000).unwrap());
+    let d: time::Duration = pts.to_duration().unwrap();
+    assert_eq!(d, time::Duration::microseconds(-100_100));
+}
+
+#[test]
+fn test_rational_from_duration() {
+    let d = std::time::Duration::from_millis(1500);
+    let pts = RationalTime::from_duration_rounded(&d, Timebase::MPEG, Rounding::Truncate).unwrap();
+    assert_eq!(pts.ticks, 135_000);
+
+    let ntsc = Timebase::new(1001, 30000).unwrap();
+    let d = std::time::Duration::from_millis(1000);
+    let floor = RationalTime::from_duration_rounded(&d, ntsc, Rounding::Truncate).unwrap();
+    let near = RationalTime::from_duration_rounded(&d, ntsc, Rounding::HalfUp).unwrap();
+    assert_eq!((floor.ticks, near.ticks), (29, 30));
+
+    let negative = DurationParts::new(true, 1, 500_000_000);
+    let pts =
+        RationalTime::from_duration_rounded(&negative, Timebase::MILLISECONDS, Rounding::Truncate)
+            .unwrap();
+    assert_eq!(pts.ticks, -1500);
+    assert_eq!(pts.to_duration_parts(), negative);
+}
@@ -0,0 +1,104 @@
+//! Rounding of durations before formatting.
+//!
+//! [`Hhmmss::rounded`](crate::Hhmmss::rounded) returns a
+//! [`DurationParts`] which itself implements [`Hhmmss`](crate::Hhmmss), so
+//! every layout can be applied to the rounded value and carries propagate
+//! through seconds, minutes and hours:
+//!
+//! ```
+//! use hhmmss_rs::fracts::Precision;
+//! use hhmmss_rs::round::Rounding;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(3599, 999_600_000);
+//! assert_eq!(d.hhmmssxxx(), "00:59:59.999");
+//! let rounded = d.rounded(Precision::MILLISECONDS, Rounding::HalfUp);
+//! assert_eq!(rounded.hhmmssxxx(), "01:00:00.000");
+//! ```
+
+use crate::fracts::{FractPartOfDuration, Precision};
+use crate::parse::DurationParts;
+
+/// How to round a duration that is not a multiple of the target unit.
+///
+/// The modes are applied to the signed value, so `Ceil` rounds -1.5 s to
+/// -1 s and `Floor` rounds it to -2 s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds towards zero, which is what the layouts do without rounding.
+    Truncate,
+    /// Rounds to the nearest value; ties are rounded away from zero.
+    HalfUp,
+    /// Rounds to the nearest value; ties are rounded to an even multiple.
+    HalfEven,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds towards negative infinity.
+    Floor,
+}
+
+/// The unit a duration is rounded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundTo {
+    /// The given number of fractional digits.
+    Fract(Precision),
+    /// Whole seconds.
+    Seconds,
+    /// Whole minutes.
+    Minutes,
+    /// Whole hours.
+    Hours,
+}
+
+impl RoundTo {
+    /// Returns the length of the unit in nanoseconds.
+    pub fn nanos(&self) -> u128 {
+        match self {
+            RoundTo::Fract(precision) => 1_000_000_000 / precision.units_per_sec() as u128,
+            RoundTo::Seconds => 1_000_000_000,
+            RoundTo::Minutes => 60_000_000_000,
+            RoundTo::Hours => 3_600_000_000_000,
+        }
+    }
+}
+
+impl From<Precision> for RoundTo {
+    fn from(precision: Precision) -> Self {
+        RoundTo::Fract(precision)
+    }
+}
+
+impl From<FractPartOfDuration> for RoundTo {
+    fn from(part: FractPartOfDuration) -> Self {
+        RoundTo::Fract(part.into())
+    }
+}
+
+/// Rounds `parts` to a multiple of `to`.
+///
+/// Results beyond the range of [`DurationParts`] saturate.
+pub fn round(parts: DurationParts, to: RoundTo, mode: Rounding) -> DurationParts {
+    let unit = to.nanos();
+    let total = parts.secs as u128 * 1_000_000_000 + parts.nanos as u128;
+    let total = div_round(total, unit, mode, parts.negative) * unit;
+    let secs = u64::try_from(total / 1_000_000_000).unwrap_or(u64::MAX);
+    DurationParts::new(parts.negative, secs, (total % 1_000_000_000) as u32)
+}
+
+/// Divides the magnitude `value` by `divisor`, rounding the signed quotient
+/// according to `mode`.
+pub(crate) fn div_round(value: u128, divisor: u128, mode: Rounding, negative: bool) -> u128 {
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    let away_from_zero = remainder != 0
+        && match mode {
+            Rounding::Truncate => false,
+            Rounding::HalfUp => remainder * 2 >= divisor,
+            Rounding::HalfEven => {
+                remainder * 2 > divisor || (remainder * 2 == divisor && quotient % 2 == 1)
+            }
+            Rounding::Ceil => !negative,
+            Rounding::Floor => negative,
+        };
+    quotient + away_from_zero as u128
+}
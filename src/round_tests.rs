@@ -0,0 +1,155 @@
+use crate::fracts::Precision;
+use crate::parse::DurationParts;
+use crate::round::{RoundTo, Rounding};
+use crate::{FractPartOfDuration, Hhmmss};
+
+#[test]
+fn test_round_carry() {
+    let d = std::time::Duration::new(3599, 999_600_000);
+    assert_eq!(d.hhmmssxxx(), "00:59:59.999");
+    let r = d.rounded(Precision::MILLISECONDS, Rounding::HalfUp);
+    assert_eq!(r.hhmmssxxx(), "01:00:00.000");
+    assert_eq!(r.mss_and_fract(Precision::CENTISECONDS), "0:00.00");
+    assert_eq!(r.total_mss(), "60:00");
+    let r = d.rounded(FractPartOfDuration::Milliseconds, Rounding::Truncate);
+    assert_eq!(r.hhmmssxxx(), "00:59:59.999");
+
+    let d = std::time::Duration::new(24 * 3600 - 1, 999_999_999);
+    let r = d.rounded(Precision::NANOSECONDS, Rounding::HalfUp);
+    assert_eq!(r, d.to_duration_parts());
+    let r = d.rounded(Precision::DECISECONDS, Rounding::Ceil);
+    assert_eq!(r.dhhmmss_and_fract(Precision::DECISECONDS), "1d 00:00:00.0");
+}
+
+#[test]
+fn test_round_modes() {
+    let cases = [
+        // (value in centiseconds, rounding to tenths, expected tenths)
+        (Rounding::Truncate, 125, 12, -12),
+        (Rounding::HalfUp, 125, 13, -13),
+        (Rounding::HalfUp, 124, 12, -12),
+        (Rounding::HalfEven, 125, 12, -12),
+        (Rounding::HalfEven, 135, 14, -14),
+        (Rounding::HalfEven, 136, 14, -14),
+        (Rounding::Ceil, 121, 13, -12),
+        (Rounding::Floor, 121, 12, -13),
+        (Rounding::Ceil, 120, 12, -12),
+    ];
+    for &(mode, centis, positive, negative) in cases.iter() {
+        let secs = centis / 100;
+        let nanos = (centis % 100) as u32 * 10_000_000;
+        for &(negate, expected) in [(false, positive), (true, negative)].iter() {
+            let parts = DurationParts::new(negate, secs, nanos);
+            let r = parts.rounded(Precision::DECISECONDS, mode);
+            let tenths = (r.secs * 10 + r.nanos as u64 / 100_000_000) as i64 * r.signum();
+            assert_eq!(tenths, expected, "{:?} {} {}", mode, centis, negate);
+        }
+    }
+}
+
+#[test]
+fn test_round_whole_units() {
+    let d = std::time::Duration::new(89, 500_000_000);
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::HalfUp).hhmmss(),
+        "00:01:30"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::HalfEven).hhmmss(),
+        "00:01:30"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Minutes, Rounding::HalfUp).hhmmss(),
+        "00:01:00"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Minutes, Rounding::Ceil).hhmmss(),
+        "00:02:00"
+    );
+    let d = std::time::Duration::new(5400, 0);
+    assert_eq!(
+        d.rounded(RoundTo::Hours, Rounding::HalfEven).hmmss(),
+        "2:00:00"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Hours, Rounding::Floor).hmmss(),
+        "1:00:00"
+    );
+}
+
+#[test]
+fn test_duration_parts_hhmmss() {
+    let parts = DurationParts::new(true, 5025, 678_901_234);
+    assert_eq!(parts.part_of_hours(), -1);
+    assert_eq!(parts.part_of_nanoseconds(), -234);
+    assert_eq!(parts.hhmmssxxx(), "-01:23:45.678");
+    assert_eq!(parts.fmt_smart(), "about -1:23:45.678");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_round_chrono() {
+    let d = chrono::Duration::milliseconds(-1_500);
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::Ceil).hhmmss(),
+        "-00:00:01"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::Floor).hhmmss(),
+        "-00:00:02"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::HalfEven).hhmmss(),
+        "-00:00:02"
+    );
+    let d = chrono::Duration::milliseconds(-500);
+    assert_eq!(
+        d.to_duration_parts(),
+        DurationParts::new(true, 0, 500_000_000)
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::HalfUp).hhmmss(),
+        "-00:00:01"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::Floor).hhmmss(),
+        "-00:00:01"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::Ceil).hhmmss(),
+        "00:00:00"
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_round_time() {
+    let d = time::Duration::new(-59, -999_500_000);
+    assert_eq!(
+        d.rounded(Precision::MILLISECONDS, Rounding::HalfUp)
+            .mssxxx(),
+        "-1:00.000"
+    );
+    assert_eq!(
+        d.rounded(Precision::MILLISECONDS, Rounding::HalfEven)
+            .mssxxx(),
+        "-1:00.000"
+    );
+    assert_eq!(
+        d.rounded(Precision::MILLISECONDS, Rounding::Ceil).mssxxx(),
+        "-0:59.999"
+    );
+    let d = time::Duration::milliseconds(-500);
+    assert_eq!(
+        d.to_duration_parts(),
+        DurationParts::new(true, 0, 500_000_000)
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::HalfUp).hhmmss(),
+        "-00:00:01"
+    );
+    assert_eq!(
+        d.rounded(RoundTo::Seconds, Rounding::Floor).hhmmss(),
+        "-00:00:01"
+    );
+}
@@ -0,0 +1,188 @@
+//! Positions in audio streams, counted in samples.
+//!
+//! A [`SamplePosition`] implements [`Hhmmss`], so every layout works on it
+//! directly. Its [`Display`] implementation writes the whole seconds
+//! followed by the samples into the current second, as in
+//! "01:23:45+22050":
+//!
+//! ```
+//! use hhmmss_rs::fracts::Precision;
+//! use hhmmss_rs::round::Rounding;
+//! use hhmmss_rs::samples::{SamplePosition, SampleRate};
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let pos = SamplePosition::new(221_624_550, SampleRate::HZ_44100);
+//! assert_eq!(pos.to_string(), "01:23:45+22050");
+//! assert_eq!(pos.hmmss_and_fract(Precision::MILLISECONDS), "1:23:45.500");
+//!
+//! let pos = SamplePosition::parse_duration("1:23:45.5", SampleRate::HZ_48000, Rounding::HalfUp);
+//! assert_eq!(pos.unwrap().samples(), 241_224_000);
+//! ```
+//!
+//! [`Display`]: fmt::Display
+
+use std::fmt;
+
+use crate::parse::{self, check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::round::{div_round, Rounding};
+use crate::Hhmmss;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// A sample rate in hertz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SampleRate(u32);
+
+impl SampleRate {
+    pub const HZ_44100: SampleRate = SampleRate(44_100);
+    pub const HZ_48000: SampleRate = SampleRate(48_000);
+    pub const HZ_96000: SampleRate = SampleRate(96_000);
+
+    /// Returns the sample rate, or `None` for zero.
+    pub const fn new(hz: u32) -> Option<SampleRate> {
+        if hz == 0 {
+            None
+        } else {
+            Some(SampleRate(hz))
+        }
+    }
+
+    pub fn hz(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the number of digits needed for the samples within a second.
+    fn width(&self) -> usize {
+        (self.0 - 1).checked_ilog10().unwrap_or(0) as usize + 1
+    }
+}
+
+/// A position in samples at a [`SampleRate`], written as
+/// "HH:MM:SS+samples".
+///
+/// The samples are zero-padded to the width of the largest sample index
+/// within a second, e.g. five digits at 44100 Hz. The hours do not wrap at
+/// 24.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SamplePosition {
+    samples: u64,
+    rate: SampleRate,
+}
+
+impl SamplePosition {
+    pub fn new(samples: u64, rate: SampleRate) -> Self {
+        Self { samples, rate }
+    }
+
+    /// Converts a duration into the nearest sample according to `mode`.
+    pub fn from_duration_rounded<T: Hhmmss + ?Sized>(
+        duration: &T,
+        rate: SampleRate,
+        mode: Rounding,
+    ) -> Result<Self, ParseError> {
+        let parts = duration.to_duration_parts();
+        if parts.negative {
+            return Err(ParseError::Negative);
+        }
+        let samples = div_round(
+            parts.to_nanos() as u128 * rate.0 as u128,
+            NANOS_PER_SEC,
+            mode,
+            false,
+        );
+        let samples = u64::try_from(samples).map_err(|_| ParseError::Overflow)?;
+        Ok(Self { samples, rate })
+    }
+
+    /// Parses any layout accepted by [`ParseHhmmss::parse_hhmmss`] and
+    /// converts it into the nearest sample according to `mode`.
+    ///
+    /// [`ParseHhmmss::parse_hhmmss`]: crate::parse::ParseHhmmss::parse_hhmmss
+    pub fn parse_duration(s: &str, rate: SampleRate, mode: Rounding) -> Result<Self, ParseError> {
+        Self::from_duration_rounded(&parse::parse(s)?, rate, mode)
+    }
+
+    /// Parses "HH:MM:SS+samples" at the given sample rate.
+    ///
+    /// The hours take two or more digits, the minutes and seconds exactly
+    /// two. The samples take any number of digits and must be below the
+    /// sample rate.
+    pub fn parse(s: &str, rate: SampleRate) -> Result<Self, ParseError> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut cursor = Cursor::new(s);
+        let (hours_pos, hours) = cursor.integer(Field::Hours)?;
+        if cursor.pos - hours_pos < 2 {
+            return Err(ParseError::InvalidWidth {
+                position: hours_pos,
+                field: Field::Hours,
+                width: cursor.pos - hours_pos,
+            });
+        }
+        cursor.expect(':')?;
+        let (minutes_pos, minutes) = cursor.two_digits(Field::Minutes)?;
+        cursor.expect(':')?;
+        let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+        cursor.expect('+')?;
+        let (samples_pos, samples) = cursor.integer(Field::Samples)?;
+        cursor.finish()?;
+        check_sexagesimal(minutes_pos, Field::Minutes, minutes)?;
+        check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+        if samples >= rate.0 as u64 {
+            return Err(ParseError::OutOfRange {
+                position: samples_pos,
+                field: Field::Samples,
+                value: samples,
+            });
+        }
+        let samples = hours
+            .checked_mul(3600)
+            .and_then(|s| s.checked_add(minutes * 60 + seconds))
+            .and_then(|s| s.checked_mul(rate.0 as u64))
+            .and_then(|s| s.checked_add(samples))
+            .ok_or(ParseError::Overflow)?;
+        Ok(Self { samples, rate })
+    }
+
+    /// Returns the number of samples since zero.
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    pub fn rate(&self) -> SampleRate {
+        self.rate
+    }
+
+    /// Returns the position, rounded up to the next nanosecond so that
+    /// converting it back with [`Rounding::Truncate`] yields the same sample.
+    pub(crate) fn parts(&self) -> DurationParts {
+        let nanos = (self.samples as u128 * NANOS_PER_SEC).div_ceil(self.rate.0 as u128);
+        DurationParts::new(
+            false,
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    }
+
+    /// Converts the position into a std, chrono or time duration.
+    pub fn to_duration<T: parse::ParseHhmmss>(&self) -> Result<T, ParseError> {
+        T::from_parts(self.parts())
+    }
+}
+
+impl fmt::Display for SamplePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rate = self.rate.0 as u64;
+        let seconds = self.samples / rate;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}+{:0width$}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            self.samples % rate,
+            width = self.rate.width()
+        )
+    }
+}
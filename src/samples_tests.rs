@@ -0,0 +1,108 @@
+use crate::fracts::Precision;
+use crate::parse::{DurationParts, Field, ParseError};
+use crate::round::Rounding;
+use crate::samples::{SamplePosition, SampleRate};
+use crate::Hhmmss;
+
+#[test]
+fn test_samples_format() {
+    let pos = SamplePosition::new(5025 * 48_000 + 7, SampleRate::HZ_48000);
+    assert_eq!(pos.to_string(), "01:23:45+00007");
+    let pos = SamplePosition::new(24 * 3600 * 96_000 + 95_999, SampleRate::HZ_96000);
+    assert_eq!(pos.to_string(), "24:00:00+95999");
+    let pos = SamplePosition::new(1234, SampleRate::new(8000).unwrap());
+    assert_eq!(pos.to_string(), "00:00:00+1234");
+    let pos = SamplePosition::new(0, SampleRate::new(1).unwrap());
+    assert_eq!(pos.to_string(), "00:00:00+0");
+    assert_eq!(SampleRate::new(0), None);
+}
+
+#[test]
+fn test_samples_layouts() {
+    let pos = SamplePosition::new(5025 * 44_100 + 22_050, SampleRate::HZ_44100);
+    assert_eq!(pos.hhmmssxxx(), "01:23:45.500");
+    assert_eq!(
+        pos.hmmss_and_fract(Precision::MICROSECONDS),
+        "1:23:45.500000"
+    );
+    assert_eq!(pos.part_of_seconds(), 45);
+    assert!(!pos.is_negative());
+
+    // One sample at 44100 Hz is 22675.73… ns; the position is rounded up
+    // so that the conversion round-trips.
+    let one = SamplePosition::new(1, SampleRate::HZ_44100);
+    assert_eq!(
+        one.to_duration_parts(),
+        DurationParts::new(false, 0, 22_676)
+    );
+    let back = SamplePosition::from_duration_rounded(
+        &one.to_duration_parts(),
+        SampleRate::HZ_44100,
+        Rounding::Truncate,
+    );
+    assert_eq!(back, Ok(one));
+    let d: std::time::Duration = one.to_duration().unwrap();
+    assert_eq!(d, std::time::Duration::from_nanos(22_676));
+}
+
+#[test]
+fn test_samples_parse() {
+    let pos = SamplePosition::parse("01:23:45+22050", SampleRate::HZ_44100).unwrap();
+    assert_eq!(pos.samples(), 5025 * 44_100 + 22_050);
+    assert_eq!(pos.rate(), SampleRate::HZ_44100);
+    let pos = SamplePosition::parse("100:00:00+7", SampleRate::HZ_48000).unwrap();
+    assert_eq!(pos.samples(), 360_000 * 48_000 + 7);
+
+    assert_eq!(
+        SamplePosition::parse("00:00:00+48000", SampleRate::HZ_48000),
+        Err(ParseError::OutOfRange {
+            position: 9,
+            field: Field::Samples,
+            value: 48_000,
+        })
+    );
+    assert_eq!(
+        SamplePosition::parse("0:00:00+0", SampleRate::HZ_48000),
+        Err(ParseError::InvalidWidth {
+            position: 0,
+            field: Field::Hours,
+            width: 1,
+        })
+    );
+    assert_eq!(
+        SamplePosition::parse("00:00:00.5", SampleRate::HZ_48000),
+        Err(ParseError::UnexpectedChar {
+            position: 8,
+            found: '.',
+        })
+    );
+    assert_eq!(
+        SamplePosition::parse("", SampleRate::HZ_48000),
+        Err(ParseError::Empty)
+    );
+}
+
+#[test]
+fn test_samples_rounding() {
+    // 0.1 ms is 4.41 samples at 44100 Hz.
+    let d = std::time::Duration::from_micros(100);
+    for (mode, expected) in [
+        (Rounding::Truncate, 4),
+        (Rounding::HalfUp, 4),
+        (Rounding::Ceil, 5),
+    ] {
+        let pos = SamplePosition::from_duration_rounded(&d, SampleRate::HZ_44100, mode).unwrap();
+        assert_eq!(pos.samples(), expected);
+    }
+
+    let parse = |s, mode| SamplePosition::parse_duration(s, SampleRate::HZ_44100, mode);
+    assert_eq!(parse("00:00.0001", Rounding::Ceil).unwrap().samples(), 5);
+    assert_eq!(
+        parse("01:00.5", Rounding::Truncate).unwrap().samples(),
+        2_668_050
+    );
+    assert_eq!(
+        parse("-00:01", Rounding::Truncate),
+        Err(ParseError::Negative)
+    );
+}
@@ -0,0 +1,316 @@
+//! SubRip (SRT) timestamps and subtitle files.
+//!
+//! SRT timestamps are written as "HH:MM:SS,mmm" and a cue is made of its
+//! index, a time range and the text:
+//!
+//! ```text
+//! 1
+//! 00:00:01,000 --> 00:00:04,500
+//! Hello, world!
+//! ```
+//!
+//! ```
+//! use hhmmss_rs::srt::Srt;
+//!
+//! let mut srt: Srt = "1\n00:00:01,000 --> 00:00:04,500\nHello, world!\n"
+//!     .parse()
+//!     .unwrap();
+//! srt.shift(&std::time::Duration::from_millis(1500)).unwrap();
+//! assert_eq!(
+//!     srt.to_string(),
+//!     "1\n00:00:02,500 --> 00:00:06,000\nHello, world!\n"
+//! );
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::Hhmmss;
+
+/// Parses an SRT timestamp such as "01:23:45,678" into [`DurationParts`].
+///
+/// The hours take one or more digits, the minutes and seconds exactly two
+/// and the milliseconds exactly three. Signs are not accepted.
+pub fn parse_timestamp(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let (_, hours) = cursor.integer(Field::Hours)?;
+    cursor.expect(':')?;
+    let (minutes_pos, minutes) = cursor.two_digits(Field::Minutes)?;
+    cursor.expect(':')?;
+    let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+    check_sexagesimal(minutes_pos, Field::Minutes, minutes)?;
+    check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+    cursor.expect(',')?;
+    let millis_pos = cursor.pos;
+    let millis = cursor.digits();
+    if millis.len() != 3 {
+        return Err(if millis.is_empty() {
+            cursor.unexpected()
+        } else {
+            ParseError::InvalidWidth {
+                position: millis_pos,
+                field: Field::Fraction,
+                width: millis.len(),
+            }
+        });
+    }
+    cursor.finish()?;
+    let secs = hours
+        .checked_mul(3600)
+        .and_then(|s| s.checked_add(minutes * 60 + seconds))
+        .ok_or(ParseError::Overflow)?;
+    let millis: u32 = millis.parse().unwrap();
+    Ok(DurationParts::new(false, secs, millis * 1_000_000))
+}
+
+/// A single subtitle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    /// The number written above the time range.
+    pub index: u64,
+    pub start: DurationParts,
+    pub end: DurationParts,
+    /// The lines of text, joined with "\n". May be empty.
+    pub text: String,
+}
+
+/// An SRT document.
+///
+/// Parse it with [`str::parse`] and write it back with its [`Display`]
+/// implementation. Line endings are written as "\n" and the timestamps are
+/// truncated to milliseconds.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Srt {
+    pub cues: Vec<Cue>,
+}
+
+/// What went wrong in an [`SrtError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SrtErrorKind {
+    /// The first line of the cue is not a number.
+    InvalidIndex,
+    /// The cue has no time range line.
+    MissingTimes,
+    /// The time range line has no "-->".
+    MissingArrow,
+    /// A timestamp could not be parsed. Positions are byte offsets into the
+    /// line.
+    Timestamp(ParseError),
+    /// The cue ends before it starts.
+    EndBeforeStart,
+    /// A shifted or rescaled time is negative.
+    Negative,
+    /// A shifted or rescaled time is too large.
+    Overflow,
+    /// [`Srt::rescale`] was asked to stretch from a zero duration.
+    RescaleFromZero,
+}
+
+/// An error pointing at the offending cue of an [`Srt`] document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SrtError {
+    /// The position of the cue in the document, starting at 1. Zero for
+    /// [`SrtErrorKind::RescaleFromZero`].
+    pub cue: usize,
+    /// The line of the input the error was found on, starting at 1. `None`
+    /// for errors of [`Srt::shift`] and [`Srt::rescale`].
+    pub line: Option<usize>,
+    pub kind: SrtErrorKind,
+}
+
+impl fmt::Display for SrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.kind != SrtErrorKind::RescaleFromZero {
+            write!(f, "cue {}", self.cue)?;
+            if let Some(line) = self.line {
+                write!(f, " (line {})", line)?;
+            }
+            f.write_str(": ")?;
+        }
+        match &self.kind {
+            SrtErrorKind::InvalidIndex => f.write_str("invalid cue index"),
+            SrtErrorKind::MissingTimes => f.write_str("missing time range"),
+            SrtErrorKind::MissingArrow => f.write_str("expected \"-->\" in time range"),
+            SrtErrorKind::Timestamp(error) => write!(f, "invalid timestamp: {}", error),
+            SrtErrorKind::EndBeforeStart => f.write_str("cue ends before it starts"),
+            SrtErrorKind::Negative => f.write_str("time is negative"),
+            SrtErrorKind::Overflow => f.write_str("time is too large"),
+            SrtErrorKind::RescaleFromZero => f.write_str("cannot rescale from a zero duration"),
+        }
+    }
+}
+
+impl std::error::Error for SrtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            SrtErrorKind::Timestamp(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Parses "start --> end" followed by optional settings, which are returned
+/// trimmed. Timestamp errors are reported relative to `line`.
+pub(crate) fn parse_time_range(
+    line: &str,
+    parse_timestamp: fn(&str) -> Result<DurationParts, ParseError>,
+) -> Result<(DurationParts, DurationParts, &str), SrtErrorKind> {
+    let arrow = line.find("-->").ok_or(SrtErrorKind::MissingArrow)?;
+    let timestamp = |from: usize, to: usize| {
+        let part = &line[from..to];
+        let offset = from + part.len() - part.trim_start().len();
+        parse_timestamp(part.trim())
+            .map_err(|error| SrtErrorKind::Timestamp(error.map_position(|p| p + offset)))
+    };
+    let after = arrow + 3;
+    let rest = &line[after..];
+    let end_start = after + rest.len() - rest.trim_start().len();
+    let end_len = line[end_start..]
+        .find(char::is_whitespace)
+        .unwrap_or(line.len() - end_start);
+    let start = timestamp(0, arrow)?;
+    let end = timestamp(end_start, end_start + end_len)?;
+    if end.to_nanos() < start.to_nanos() {
+        return Err(SrtErrorKind::EndBeforeStart);
+    }
+    Ok((start, end, line[end_start + end_len..].trim()))
+}
+
+impl FromStr for Srt {
+    type Err = SrtError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+        let mut cues = Vec::new();
+        let mut lines = s.lines().enumerate().peekable();
+        loop {
+            while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {}
+            let Some((index_line, index)) = lines.next() else {
+                break;
+            };
+            let error = |line: usize, kind| SrtError {
+                cue: cues.len() + 1,
+                line: Some(line + 1),
+                kind,
+            };
+            let index = index
+                .trim()
+                .parse()
+                .map_err(|_| error(index_line, SrtErrorKind::InvalidIndex))?;
+            let (times_line, times) = lines
+                .next_if(|(_, line)| !line.trim().is_empty())
+                .ok_or_else(|| error(index_line + 1, SrtErrorKind::MissingTimes))?;
+            // Anything after the end time, such as SRT coordinates, is dropped.
+            let (start, end, _) =
+                parse_time_range(times, parse_timestamp).map_err(|kind| error(times_line, kind))?;
+            let mut text = String::new();
+            while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(line);
+            }
+            cues.push(Cue {
+                index,
+                start,
+                end,
+                text,
+            });
+        }
+        Ok(Srt { cues })
+    }
+}
+
+impl fmt::Display for Srt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, cue) in self.cues.iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            writeln!(
+                f,
+                "{}\n{} --> {}",
+                cue.index,
+                cue.start.display_srt(),
+                cue.end.display_srt()
+            )?;
+            if !cue.text.is_empty() {
+                writeln!(f, "{}", cue.text)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Srt {
+    /// Numbers the cues from 1 in their current order.
+    pub fn renumber(&mut self) {
+        for (i, cue) in self.cues.iter_mut().enumerate() {
+            cue.index = i as u64 + 1;
+        }
+    }
+
+    /// Moves every cue by `offset`, which may be negative.
+    ///
+    /// Nothing is changed if any cue would start before zero.
+    pub fn shift<T: Hhmmss + ?Sized>(&mut self, offset: &T) -> Result<(), SrtError> {
+        let offset = offset.to_duration_parts().to_nanos();
+        self.map_times(|t| t.checked_add(offset))
+    }
+
+    /// Stretches the timeline linearly so that `from` ends up at `to`, e.g.
+    /// to fix subtitles made for a different frame rate. Every time `t`
+    /// becomes `t * to / from`, truncated to nanoseconds.
+    ///
+    /// Nothing is changed if any time would be out of range or if `from` is
+    /// zero.
+    pub fn rescale<T, U>(&mut self, from: &T, to: &U) -> Result<(), SrtError>
+    where
+        T: Hhmmss + ?Sized,
+        U: Hhmmss + ?Sized,
+    {
+        let from = from.to_duration_parts().to_nanos();
+        let to = to.to_duration_parts().to_nanos();
+        if from == 0 {
+            return Err(SrtError {
+                cue: 0,
+                line: None,
+                kind: SrtErrorKind::RescaleFromZero,
+            });
+        }
+        self.map_times(|t| t.checked_mul(to).map(|t| t / from))
+    }
+
+    fn map_times(&mut self, map: impl Fn(i128) -> Option<i128>) -> Result<(), SrtError> {
+        let convert = |cue: usize, t: DurationParts| {
+            let error = |kind| SrtError {
+                cue: cue + 1,
+                line: None,
+                kind,
+            };
+            let nanos = map(t.to_nanos()).ok_or(error(SrtErrorKind::Overflow))?;
+            if nanos < 0 {
+                return Err(error(SrtErrorKind::Negative));
+            }
+            DurationParts::from_nanos(nanos).map_err(|_| error(SrtErrorKind::Overflow))
+        };
+        let times = self
+            .cues
+            .iter()
+            .enumerate()
+            .map(|(i, cue)| Ok((convert(i, cue.start)?, convert(i, cue.end)?)))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (cue, (start, end)) in self.cues.iter_mut().zip(times) {
+            cue.start = start;
+            cue.end = end;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use crate::parse::{DurationParts, Field, ParseError, ParseHhmmss};
+use crate::srt::{parse_timestamp, Srt, SrtError, SrtErrorKind};
+use crate::Hhmmss;
+
+const SAMPLE: &str = "1
+00:00:01,000 --> 00:00:04,500
+Hello, world!
+
+2
+00:01:02,003 --> 01:00:00,000
+Two
+lines
+";
+
+#[test]
+fn test_fmt_srt() {
+    let d = std::time::Duration::new(5025, 678_901_234);
+    assert_eq!(d.fmt_srt(), "01:23:45,678");
+    assert_eq!(std::time::Duration::new(0, 0).fmt_srt(), "00:00:00,000");
+    assert_eq!(
+        std::time::Duration::new(360_000, 0).fmt_srt(),
+        "100:00:00,000"
+    );
+}
+
+#[test]
+fn test_parse_srt_timestamp() {
+    assert_eq!(
+        parse_timestamp("01:23:45,678"),
+        Ok(DurationParts::new(false, 5025, 678_000_000))
+    );
+    assert_eq!(
+        std::time::Duration::parse_srt("100:00:00,001"),
+        Ok(std::time::Duration::new(360_000, 1_000_000))
+    );
+    assert_eq!(
+        parse_timestamp("01:23:45.678"),
+        Err(ParseError::UnexpectedChar {
+            position: 8,
+            found: '.'
+        })
+    );
+    assert_eq!(
+        parse_timestamp("01:23:45,67"),
+        Err(ParseError::InvalidWidth {
+            position: 9,
+            field: Field::Fraction,
+            width: 2
+        })
+    );
+    assert_eq!(
+        parse_timestamp("01:23:60,000"),
+        Err(ParseError::OutOfRange {
+            position: 6,
+            field: Field::Seconds,
+            value: 60
+        })
+    );
+    assert_eq!(parse_timestamp(""), Err(ParseError::Empty));
+}
+
+#[test]
+fn test_srt_round_trip() {
+    let srt: Srt = SAMPLE.parse().unwrap();
+    assert_eq!(srt.cues.len(), 2);
+    assert_eq!(srt.cues[0].index, 1);
+    assert_eq!(srt.cues[0].start, DurationParts::new(false, 1, 0));
+    assert_eq!(srt.cues[0].end, DurationParts::new(false, 4, 500_000_000));
+    assert_eq!(srt.cues[1].text, "Two\nlines");
+    assert_eq!(srt.to_string(), SAMPLE);
+
+    let crlf = format!("\u{feff}{}\r\n\r\n", SAMPLE.replace('\n', "\r\n"));
+    assert_eq!(crlf.parse::<Srt>(), Ok(srt));
+}
+
+#[test]
+fn test_srt_shift_and_rescale() {
+    let mut srt: Srt = SAMPLE.parse().unwrap();
+    srt.shift(&std::time::Duration::from_millis(500)).unwrap();
+    assert_eq!(srt.cues[0].start.fmt_srt(), "00:00:01,500");
+    assert_eq!(srt.cues[1].end.fmt_srt(), "01:00:00,500");
+
+    srt.shift(&DurationParts::new(true, 1, 0)).unwrap();
+    assert_eq!(srt.cues[0].start.fmt_srt(), "00:00:00,500");
+    let before = srt.clone();
+    assert_eq!(
+        srt.shift(&DurationParts::new(true, 1, 0)),
+        Err(SrtError {
+            cue: 1,
+            line: None,
+            kind: SrtErrorKind::Negative
+        })
+    );
+    assert_eq!(srt, before);
+
+    // 25 fps subtitles played at 23.976 fps: stretch by 25 / 23.976.
+    let mut srt: Srt = SAMPLE.parse().unwrap();
+    srt.rescale(
+        &std::time::Duration::new(23, 976_000_000),
+        &std::time::Duration::new(25, 0),
+    )
+    .unwrap();
+    assert_eq!(srt.cues[0].start.fmt_srt(), "00:00:01,042");
+    assert_eq!(srt.cues[1].end.fmt_srt(), "01:02:33,753");
+
+    let before = srt.clone();
+    let err = srt
+        .rescale(&std::time::Duration::ZERO, &std::time::Duration::new(25, 0))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SrtError {
+            cue: 0,
+            line: None,
+            kind: SrtErrorKind::RescaleFromZero
+        }
+    );
+    assert_eq!(err.to_string(), "cannot rescale from a zero duration");
+    assert_eq!(srt, before);
+}
+
+#[test]
+fn test_srt_errors() {
+    let err = "1\n00:00:01,000 --> 00:00:02,000\nok\n\nx\n00:00:03,000 --> 00:00:04,000\n"
+        .parse::<Srt>()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SrtError {
+            cue: 2,
+            line: Some(5),
+            kind: SrtErrorKind::InvalidIndex
+        }
+    );
+    assert_eq!(err.to_string(), "cue 2 (line 5): invalid cue index");
+
+    assert_eq!(
+        "1\n00:00:01,000 -> 00:00:02,000\n".parse::<Srt>(),
+        Err(SrtError {
+            cue: 1,
+            line: Some(2),
+            kind: SrtErrorKind::MissingArrow
+        })
+    );
+    assert_eq!(
+        "1\n00:00:01,000 --> 00:00:02;000\n".parse::<Srt>(),
+        Err(SrtError {
+            cue: 1,
+            line: Some(2),
+            kind: SrtErrorKind::Timestamp(ParseError::UnexpectedChar {
+                position: 25,
+                found: ';'
+            })
+        })
+    );
+    assert_eq!(
+        "1\n00:00:03,000 --> 00:00:02,000\n".parse::<Srt>(),
+        Err(SrtError {
+            cue: 1,
+            line: Some(2),
+            kind: SrtErrorKind::EndBeforeStart
+        })
+    );
+    assert_eq!(
+        "1\n".parse::<Srt>(),
+        Err(SrtError {
+            cue: 1,
+            line: Some(2),
+            kind: SrtErrorKind::MissingTimes
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_srt_chrono() {
+    let d = chrono::Duration::milliseconds(5_025_678);
+    assert_eq!(d.fmt_srt(), "01:23:45,678");
+    assert_eq!(chrono::Duration::parse_srt("01:23:45,678"), Ok(d));
+    let mut srt: Srt = SAMPLE.parse().unwrap();
+    srt.shift(&chrono::Duration::milliseconds(-1000)).unwrap();
+    assert_eq!(srt.cues[0].start.fmt_srt(), "00:00:00,000");
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_srt_time() {
+    let d = time::Duration::milliseconds(5_025_678);
+    assert_eq!(d.fmt_srt(), "01:23:45,678");
+    assert_eq!(time::Duration::parse_srt("01:23:45,678"), Ok(d));
+}
@@ -0,0 +1,267 @@
+//! systemd time spans such as "1h 30min 5s", as written by systemd's
+//! `format_timespan()` and read by `parse_sec()` in unit files.
+//!
+//! systemd counts in microseconds; finer digits are truncated. A month is
+//! 30.44 days and a year 365.25 days.
+//!
+//! ```
+//! use hhmmss_rs::parse::ParseHhmmss as _;
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(5405, 500_000_000);
+//! assert_eq!(d.fmt_systemd(), "1h 30min 5.500000s");
+//! let ms = std::time::Duration::from_millis(1);
+//! assert_eq!(d.fmt_systemd_with_accuracy(&ms), "1h 30min 5.500s");
+//! assert_eq!(
+//!     std::time::Duration::parse_systemd("1h 30min 5.5s"),
+//!     Ok(d)
+//! );
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::parse::{Cursor, DurationParts, ParseError};
+use crate::Hhmmss;
+
+const USEC_PER_SEC: u64 = 1_000_000;
+const USEC_PER_MINUTE: u64 = 60 * USEC_PER_SEC;
+const USEC_PER_HOUR: u64 = 60 * USEC_PER_MINUTE;
+const USEC_PER_DAY: u64 = 24 * USEC_PER_HOUR;
+const USEC_PER_WEEK: u64 = 7 * USEC_PER_DAY;
+const USEC_PER_MONTH: u64 = 2_629_800 * USEC_PER_SEC;
+const USEC_PER_YEAR: u64 = 31_557_600 * USEC_PER_SEC;
+/// systemd's `USEC_INFINITY`.
+const USEC_INFINITY: u64 = u64::MAX;
+
+/// Returns the absolute value of a duration in whole microseconds, or
+/// `None` if it does not fit below `USEC_INFINITY`.
+fn to_usec<T: Hhmmss + ?Sized>(duration: &T) -> Option<u64> {
+    let parts = duration.to_duration_parts();
+    parts
+        .secs
+        .checked_mul(USEC_PER_SEC)
+        .and_then(|usec| usec.checked_add(parts.nanos as u64 / 1_000))
+        .filter(|&usec| usec != USEC_INFINITY)
+}
+
+/// Writes a duration like systemd's `format_timespan()`. Negative durations,
+/// which systemd does not have, are prefixed with "-".
+pub struct DisplaySystemd<'a, T: ?Sized> {
+    pub(crate) duration: &'a T,
+    /// In microseconds.
+    pub(crate) accuracy: u64,
+}
+
+impl<T: ?Sized> DisplaySystemd<'_, T> {
+    /// Stops writing units once the rest is below `accuracy`, and limits the
+    /// fractional digits of the seconds to it. Defaults to one
+    /// microsecond.
+    pub fn with_accuracy<A: Hhmmss + ?Sized>(mut self, accuracy: &A) -> Self {
+        self.accuracy = to_usec(accuracy).unwrap_or(USEC_INFINITY);
+        self
+    }
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for DisplaySystemd<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [(&str, u64); 9] = [
+            ("y", USEC_PER_YEAR),
+            ("month", USEC_PER_MONTH),
+            ("w", USEC_PER_WEEK),
+            ("d", USEC_PER_DAY),
+            ("h", USEC_PER_HOUR),
+            ("min", USEC_PER_MINUTE),
+            ("s", USEC_PER_SEC),
+            ("ms", 1_000),
+            ("us", 1),
+        ];
+        let parts = self.duration.to_duration_parts();
+        let Some(mut t) = to_usec(&parts) else {
+            return f.write_str("infinity");
+        };
+        if t == 0 {
+            return f.write_char('0');
+        }
+        if parts.negative {
+            f.write_char('-')?;
+        }
+        let mut something = false;
+        for (suffix, unit) in UNITS {
+            if t == 0 || (t < self.accuracy && something) {
+                break;
+            }
+            if t < unit {
+                continue;
+            }
+            if something {
+                f.write_char(' ')?;
+            }
+            let whole = t / unit;
+            let mut rest = t % unit;
+            let mut done = false;
+            // Below a minute, the rest is written as a fraction of the unit
+            // with as many digits as the accuracy allows.
+            if t < USEC_PER_MINUTE && rest > 0 {
+                let mut digits = unit.ilog10() as i32;
+                let mut accuracy = self.accuracy;
+                while accuracy > 1 {
+                    rest /= 10;
+                    digits -= 1;
+                    accuracy /= 10;
+                }
+                if digits > 0 {
+                    write!(
+                        f,
+                        "{}.{:0width$}{}",
+                        whole,
+                        rest,
+                        suffix,
+                        width = digits as usize
+                    )?;
+                    t = 0;
+                    done = true;
+                }
+            }
+            if !done {
+                write!(f, "{}{}", whole, suffix)?;
+                // Like systemd, continue with the rest as shortened above.
+                t = rest;
+            }
+            something = true;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a systemd time span into [`DurationParts`], following
+/// `parse_sec()`.
+///
+/// The span is a sequence of numbers, each with an optional unit and
+/// optionally separated by whitespace, such as "2h 30min", "1.5d" or
+/// "5min20s". Numbers without a unit are seconds. The units are:
+///
+/// - "usec", "us", "µs", "μs",
+/// - "msec", "ms",
+/// - "seconds", "second", "sec", "s",
+/// - "minutes", "minute", "min", "m",
+/// - "hours", "hour", "hr", "h",
+/// - "days", "day", "d",
+/// - "weeks", "week", "w",
+/// - "months", "month", "M" (30.44 days),
+/// - "years", "year", "y" (365.25 days).
+///
+/// Fractions are truncated to microseconds. "infinity" and spans of
+/// `u64::MAX` microseconds or more are rejected with
+/// [`ParseError::Overflow`], negative spans with [`ParseError::Negative`].
+pub fn parse(s: &str) -> Result<DurationParts, ParseError> {
+    // Ordered so that every unit comes before its prefixes.
+    const UNITS: [(&str, u64); 30] = [
+        ("seconds", USEC_PER_SEC),
+        ("second", USEC_PER_SEC),
+        ("sec", USEC_PER_SEC),
+        ("s", USEC_PER_SEC),
+        ("minutes", USEC_PER_MINUTE),
+        ("minute", USEC_PER_MINUTE),
+        ("min", USEC_PER_MINUTE),
+        ("months", USEC_PER_MONTH),
+        ("month", USEC_PER_MONTH),
+        ("M", USEC_PER_MONTH),
+        ("msec", 1_000),
+        ("ms", 1_000),
+        ("m", USEC_PER_MINUTE),
+        ("hours", USEC_PER_HOUR),
+        ("hour", USEC_PER_HOUR),
+        ("hr", USEC_PER_HOUR),
+        ("h", USEC_PER_HOUR),
+        ("days", USEC_PER_DAY),
+        ("day", USEC_PER_DAY),
+        ("d", USEC_PER_DAY),
+        ("weeks", USEC_PER_WEEK),
+        ("week", USEC_PER_WEEK),
+        ("w", USEC_PER_WEEK),
+        ("years", USEC_PER_YEAR),
+        ("year", USEC_PER_YEAR),
+        ("y", USEC_PER_YEAR),
+        ("usec", 1),
+        ("us", 1),
+        ("μs", 1),
+        ("µs", 1),
+    ];
+    let mut cursor = Cursor::new(s);
+    let skip_whitespace = |cursor: &mut Cursor| {
+        while cursor.peek().is_some_and(char::is_whitespace) {
+            cursor.bump();
+        }
+    };
+    skip_whitespace(&mut cursor);
+    if cursor.rest().starts_with("infinity") {
+        return Err(ParseError::Overflow);
+    }
+    let mut total: u64 = 0;
+    let mut something = false;
+    loop {
+        skip_whitespace(&mut cursor);
+        if cursor.is_at_end() {
+            if !something {
+                return Err(if s.is_empty() {
+                    ParseError::Empty
+                } else {
+                    cursor.unexpected()
+                });
+            }
+            break;
+        }
+        if cursor.peek() == Some('-') {
+            return Err(ParseError::Negative);
+        }
+        cursor.eat('+');
+        let number_pos = cursor.pos;
+        let whole = cursor.digits();
+        let fract = if cursor.eat('.') {
+            let fract = cursor.digits();
+            if fract.is_empty() {
+                return Err(cursor.unexpected());
+            }
+            fract
+        } else if whole.is_empty() {
+            cursor.pos = number_pos;
+            return Err(cursor.unexpected());
+        } else {
+            ""
+        };
+        let whole: u64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| ParseError::Overflow)?
+        };
+        let number_end = cursor.pos;
+        skip_whitespace(&mut cursor);
+        let unit = match UNITS.iter().find(|(unit, _)| cursor.eat_str(unit)) {
+            Some((_, unit)) => *unit,
+            // A space or the end must follow a number without a unit, so
+            // that "12.34.56" is rejected.
+            None if cursor.pos == number_end && !cursor.is_at_end() => {
+                return Err(cursor.unexpected())
+            }
+            None => USEC_PER_SEC,
+        };
+        let mut value = whole.checked_mul(unit).ok_or(ParseError::Overflow)?;
+        let mut place = unit / 10;
+        for digit in fract.bytes() {
+            value = value
+                .checked_add((digit - b'0') as u64 * place)
+                .ok_or(ParseError::Overflow)?;
+            place /= 10;
+        }
+        total = total
+            .checked_add(value)
+            .filter(|&total| total != USEC_INFINITY)
+            .ok_or(ParseError::Overflow)?;
+        something = true;
+    }
+    Ok(DurationParts::new(
+        false,
+        total / USEC_PER_SEC,
+        (total % USEC_PER_SEC) as u32 * 1_000,
+    ))
+}
@@ -0,0 +1,159 @@
+use crate::parse::{DurationParts, ParseError, ParseHhmmss};
+use crate::systemd::parse;
+use crate::Hhmmss;
+
+fn usec(n: u64) -> DurationParts {
+    DurationParts::new(false, n / 1_000_000, (n % 1_000_000) as u32 * 1_000)
+}
+
+const SEC: u64 = 1_000_000;
+const YEAR: u64 = 31_557_600 * SEC;
+const MONTH: u64 = 2_629_800 * SEC;
+
+#[test]
+fn test_fmt_systemd() {
+    for (n, expected) in [
+        (0, "0"),
+        (1, "1us"),
+        (1_500, "1.500ms"),
+        (SEC, "1s"),
+        (SEC + 1, "1.000001s"),
+        (60 * SEC, "1min"),
+        (5_405_500_000, "1h 30min 5.500000s"),
+        (2 * 86_400 * SEC, "2d"),
+        (YEAR + 2 * MONTH, "1y 2month"),
+        (90 * 86_400 * SEC, "2month 4w 1d 3h"),
+        (61 * SEC + 1_500, "1min 1.001500s"),
+    ] {
+        assert_eq!(usec(n).fmt_systemd(), expected);
+    }
+    assert_eq!(std::time::Duration::from_nanos(1_999).fmt_systemd(), "1us");
+    assert_eq!(DurationParts::new(true, 2, 0).fmt_systemd(), "-2s");
+    assert_eq!(std::time::Duration::MAX.fmt_systemd(), "infinity");
+    #[cfg(feature = "chrono")]
+    assert_eq!(chrono::Duration::milliseconds(-700).fmt_systemd(), "-700ms");
+    #[cfg(feature = "time")]
+    assert_eq!(time::Duration::milliseconds(-700).fmt_systemd(), "-700ms");
+}
+
+#[test]
+fn test_fmt_systemd_with_accuracy() {
+    let d = usec(5_405_500_000);
+    for (accuracy, expected) in [
+        (std::time::Duration::from_micros(1), "1h 30min 5.500000s"),
+        (std::time::Duration::from_millis(1), "1h 30min 5.500s"),
+        (std::time::Duration::from_millis(100), "1h 30min 5.5s"),
+        (std::time::Duration::from_secs(1), "1h 30min 5s"),
+        (std::time::Duration::from_secs(60), "1h 30min"),
+        (std::time::Duration::from_secs(86_400), "1h"),
+    ] {
+        assert_eq!(d.fmt_systemd_with_accuracy(&accuracy), expected);
+    }
+    // Like systemd, the rest is lost once the fraction has no digits left.
+    let ms = std::time::Duration::from_millis(1);
+    assert_eq!(usec(1_500).fmt_systemd_with_accuracy(&ms), "1ms");
+    assert_eq!(
+        usec(61 * SEC + 1_500).fmt_systemd_with_accuracy(&ms),
+        "1min 1.001s"
+    );
+}
+
+#[test]
+fn test_parse_systemd() {
+    // Mostly the valid cases of systemd's `test_parse_sec`.
+    for (s, n) in [
+        ("5s", 5 * SEC),
+        ("5s500ms", 5_500_000),
+        (" 5s 500ms  ", 5_500_000),
+        (" 5.5s  ", 5_500_000),
+        (" 5.5s 0.5ms ", 5_500_500),
+        (" .22s ", 220_000),
+        (" .50y ", YEAR / 2),
+        ("2.5", 2_500_000),
+        (".7", 700_000),
+        ("+3", 3 * SEC),
+        ("1 2", 3 * SEC),
+        ("12.34 .5", 12_840_000),
+        ("23us", 23),
+        ("23usec", 23),
+        ("23μs", 23),
+        ("23µs", 23),
+        ("7msec", 7_000),
+        ("1 sec", SEC),
+        ("2seconds 1second", 3 * SEC),
+        ("1.5min", 90 * SEC),
+        ("2m", 120 * SEC),
+        ("1minute 2minutes", 180 * SEC),
+        ("1h 30min 5s", 5_405 * SEC),
+        ("1hr", 3_600 * SEC),
+        ("1hour 2hours", 10_800 * SEC),
+        ("2d", 172_800 * SEC),
+        ("1day 1days", 172_800 * SEC),
+        ("1w 1week 1weeks", 21 * 86_400 * SEC),
+        ("1M", MONTH),
+        ("1y 2month", YEAR + 2 * MONTH),
+        ("1months", MONTH),
+        ("1year 1years", 2 * YEAR),
+        ("0", 0),
+    ] {
+        assert_eq!(parse(s), Ok(usec(n)), "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_systemd_errors() {
+    assert_eq!(parse(""), Err(ParseError::Empty));
+    assert_eq!(parse("   "), Err(ParseError::UnexpectedEnd { position: 3 }));
+    assert_eq!(parse("3."), Err(ParseError::UnexpectedEnd { position: 2 }));
+    for (s, position, found) in [
+        ("12.34.56", 5, '.'),
+        (" 3.. 0", 3, '.'),
+        (" .s ", 2, 's'),
+        ("3.+1", 2, '+'),
+        ("3. 1", 2, ' '),
+        ("3.sec", 2, 's'),
+        ("5mo", 2, 'o'),
+        ("s", 0, 's'),
+        ("1h x", 3, 'x'),
+    ] {
+        assert_eq!(
+            parse(s),
+            Err(ParseError::UnexpectedChar { position, found }),
+            "{}",
+            s
+        );
+    }
+    for s in ["-1s", " -0", "1s -1s"] {
+        assert_eq!(parse(s), Err(ParseError::Negative), "{}", s);
+    }
+    for s in [
+        "infinity",
+        " infinity ",
+        "600000y",
+        "18446744073709551616us",
+        "18446744073709551615us",
+    ] {
+        assert_eq!(parse(s), Err(ParseError::Overflow), "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_systemd_into_durations() {
+    assert_eq!(
+        std::time::Duration::parse_systemd("1h 30min"),
+        Ok(std::time::Duration::from_secs(5_400))
+    );
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        chrono::Duration::parse_systemd("500ms"),
+        Ok(chrono::Duration::milliseconds(500))
+    );
+}
+
+#[test]
+fn test_fmt_systemd_round_trip() {
+    for n in [1, 999, 1_500, 59 * SEC + 1, 5_405_500_000, YEAR + MONTH + 7] {
+        let d = usec(n);
+        assert_eq!(parse(&d.fmt_systemd()), Ok(d), "{}", n);
+    }
+}
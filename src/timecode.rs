@@ -0,0 +1,314 @@
+//! SMPTE timecodes such as "01:23:45:12".
+//!
+//! A [`Timecode`] is a whole number of frames at a [`FrameRate`]. Durations
+//! rarely fall on a frame boundary, so converting one either returns the
+//! sub-frame remainder or rounds explicitly:
+//!
+//! ```
+//! use hhmmss_rs::round::Rounding;
+//! use hhmmss_rs::timecode::{FrameRate, Timecode};
+//!
+//! let d = std::time::Duration::new(5025, 500_000_000);
+//! let (tc, remainder) = Timecode::from_duration(&d, FrameRate::FPS_24).unwrap();
+//! assert_eq!(tc.to_string(), "01:23:45:12");
+//! assert_eq!(remainder.nanos, 0);
+//!
+//! let d = std::time::Duration::new(5025, 520_000_000);
+//! let tc = Timecode::from_duration_rounded(&d, FrameRate::FPS_25, Rounding::HalfUp).unwrap();
+//! assert_eq!(tc.to_string(), "01:23:45:13");
+//! ```
+//!
+//! NTSC rates run at exactly 30000/1001 or 60000/1001 frames per second.
+//! Their drop-frame variants skip the frame numbers 0 and 1 (0 to 3 at
+//! 59.94 fps) at the start of every minute except every tenth, so that the
+//! timecode stays close to the wall clock, and use ";" before the frames:
+//!
+//! ```
+//! use hhmmss_rs::timecode::{FrameRate, Timecode};
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let tc = Timecode::parse("01:00:00;00", FrameRate::FPS_29_97_DF).unwrap();
+//! assert_eq!(tc.frames(), 107_892);
+//! assert_eq!(tc.to_duration_parts().hhmmssxxx(), "00:59:59.996");
+//! ```
+
+use std::fmt;
+
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError, ParseHhmmss};
+use crate::round::{div_round, Rounding};
+use crate::Hhmmss;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// A frame rate, stored as the exact ratio of frames per second, and
+/// whether timecodes at this rate are counted in drop-frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameRate {
+    num: u32,
+    den: u32,
+    drop_frame: bool,
+}
+
+impl FrameRate {
+    pub const FPS_23_976: FrameRate = FrameRate::ntsc(24, false);
+    pub const FPS_24: FrameRate = FrameRate::whole(24);
+    pub const FPS_25: FrameRate = FrameRate::whole(25);
+    /// 29.97 fps, non-drop-frame.
+    pub const FPS_29_97: FrameRate = FrameRate::ntsc(30, false);
+    /// 29.97 fps, drop-frame.
+    pub const FPS_29_97_DF: FrameRate = FrameRate::ntsc(30, true);
+    pub const FPS_30: FrameRate = FrameRate::whole(30);
+    pub const FPS_50: FrameRate = FrameRate::whole(50);
+    /// 59.94 fps, non-drop-frame.
+    pub const FPS_59_94: FrameRate = FrameRate::ntsc(60, false);
+    /// 59.94 fps, drop-frame.
+    pub const FPS_59_94_DF: FrameRate = FrameRate::ntsc(60, true);
+    pub const FPS_60: FrameRate = FrameRate::whole(60);
+
+    const fn whole(fps: u32) -> FrameRate {
+        FrameRate {
+            num: fps,
+            den: 1,
+            drop_frame: false,
+        }
+    }
+
+    const fn ntsc(nominal: u32, drop_frame: bool) -> FrameRate {
+        FrameRate {
+            num: nominal * 1000,
+            den: 1001,
+            drop_frame,
+        }
+    }
+
+    /// Returns a whole frame rate, or `None` for zero.
+    pub const fn new(fps: u32) -> Option<FrameRate> {
+        if fps == 0 {
+            None
+        } else {
+            Some(FrameRate::whole(fps))
+        }
+    }
+
+    /// Returns the non-drop-frame rate of `num / den` frames per second, or
+    /// `None` if either is zero.
+    pub const fn from_ratio(num: u32, den: u32) -> Option<FrameRate> {
+        if num == 0 || den == 0 {
+            None
+        } else {
+            Some(FrameRate {
+                num,
+                den,
+                drop_frame: false,
+            })
+        }
+    }
+
+    /// Returns the drop-frame variant of this rate, or `None` unless it is
+    /// a multiple of 30000/1001 frames per second such as 29.97 or 59.94.
+    pub fn drop_frame(self) -> Option<FrameRate> {
+        let nominal = self.nominal();
+        if self.den == 1001 && nominal.is_multiple_of(30) && self.num == nominal * 1000 {
+            Some(FrameRate {
+                drop_frame: true,
+                ..self
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` for drop-frame rates.
+    pub fn is_drop_frame(&self) -> bool {
+        self.drop_frame
+    }
+
+    /// Returns the frame numbers skipped per minute in drop-frame: 2 at
+    /// 29.97 fps and 4 at 59.94 fps. Zero for non-drop-frame rates.
+    fn dropped_per_minute(&self) -> u64 {
+        if self.drop_frame {
+            self.nominal() as u64 / 15
+        } else {
+            0
+        }
+    }
+
+    /// Returns the frames per second as a fraction `(numerator,
+    /// denominator)`.
+    pub fn ratio(&self) -> (u32, u32) {
+        (self.num, self.den)
+    }
+
+    /// Returns the frames counted per timecode second, i.e. the frame rate
+    /// rounded up to a whole number.
+    pub fn nominal(&self) -> u32 {
+        self.num.div_ceil(self.den)
+    }
+}
+
+/// A position in frames at a [`FrameRate`], written as "HH:MM:SS:FF" or,
+/// in drop-frame, "HH:MM:SS;FF".
+///
+/// The hours do not wrap at 24.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timecode {
+    frames: u64,
+    rate: FrameRate,
+}
+
+impl Timecode {
+    /// Creates a timecode from a frame count.
+    pub fn from_frames(frames: u64, rate: FrameRate) -> Self {
+        Self { frames, rate }
+    }
+
+    /// Converts a duration into the last frame that started at or before
+    /// it, and returns the remainder within that frame.
+    ///
+    /// `duration` equals the frame's [`Timecode::to_duration_parts`] plus
+    /// the remainder.
+    pub fn from_duration<T: Hhmmss + ?Sized>(
+        duration: &T,
+        rate: FrameRate,
+    ) -> Result<(Self, DurationParts), ParseError> {
+        let tc = Self::from_duration_rounded(duration, rate, Rounding::Truncate)?;
+        let nanos = duration.to_duration_parts().to_nanos() - tc.to_duration_parts().to_nanos();
+        Ok((tc, DurationParts::from_nanos(nanos)?))
+    }
+
+    /// Converts a duration into the nearest frame according to `mode`.
+    pub fn from_duration_rounded<T: Hhmmss + ?Sized>(
+        duration: &T,
+        rate: FrameRate,
+        mode: Rounding,
+    ) -> Result<Self, ParseError> {
+        let parts = duration.to_duration_parts();
+        if parts.negative {
+            return Err(ParseError::Negative);
+        }
+        let nanos = parts.to_nanos() as u128;
+        let frames = div_round(
+            nanos * rate.num as u128,
+            NANOS_PER_SEC * rate.den as u128,
+            mode,
+            false,
+        );
+        let frames = u64::try_from(frames).map_err(|_| ParseError::Overflow)?;
+        Ok(Self { frames, rate })
+    }
+
+    /// Parses "HH:MM:SS:FF", or "HH:MM:SS;FF" for drop-frame rates, at the
+    /// given frame rate.
+    ///
+    /// The hours take two or more digits, the other components exactly two.
+    /// The frames must be below [`FrameRate::nominal`] and must not be one
+    /// of the frame numbers skipped in drop-frame.
+    pub fn parse(s: &str, rate: FrameRate) -> Result<Self, ParseError> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut cursor = Cursor::new(s);
+        let (hours_pos, hours) = cursor.integer(Field::Hours)?;
+        if cursor.pos - hours_pos < 2 {
+            return Err(ParseError::InvalidWidth {
+                position: hours_pos,
+                field: Field::Hours,
+                width: cursor.pos - hours_pos,
+            });
+        }
+        cursor.expect(':')?;
+        let (minutes_pos, minutes) = cursor.two_digits(Field::Minutes)?;
+        cursor.expect(':')?;
+        let (seconds_pos, seconds) = cursor.two_digits(Field::Seconds)?;
+        cursor.expect(if rate.drop_frame { ';' } else { ':' })?;
+        let (frames_pos, frames) = cursor.two_digits(Field::Frames)?;
+        cursor.finish()?;
+        check_sexagesimal(minutes_pos, Field::Minutes, minutes)?;
+        check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+        let nominal = rate.nominal() as u64;
+        let dropped = rate.dropped_per_minute();
+        if frames >= nominal || (seconds == 0 && frames < dropped && minutes % 10 != 0) {
+            return Err(ParseError::OutOfRange {
+                position: frames_pos,
+                field: Field::Frames,
+                value: frames,
+            });
+        }
+        let total_minutes = hours
+            .checked_mul(60)
+            .and_then(|m| m.checked_add(minutes))
+            .ok_or(ParseError::Overflow)?;
+        let frames = hours
+            .checked_mul(3600)
+            .and_then(|s| s.checked_add(minutes * 60 + seconds))
+            .and_then(|s| s.checked_mul(nominal))
+            .and_then(|f| f.checked_add(frames))
+            .ok_or(ParseError::Overflow)?
+            - dropped * (total_minutes - total_minutes / 10);
+        Ok(Self { frames, rate })
+    }
+
+    /// Returns the number of frames since zero.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    pub fn rate(&self) -> FrameRate {
+        self.rate
+    }
+
+    /// Returns the start of the frame, rounded up to the next nanosecond so
+    /// that converting it back yields the same frame.
+    pub fn to_duration_parts(&self) -> DurationParts {
+        let nanos = (self.frames as u128 * NANOS_PER_SEC * self.rate.den as u128)
+            .div_ceil(self.rate.num as u128);
+        DurationParts::new(
+            false,
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    }
+
+    /// Converts the start of the frame into a std, chrono or time duration.
+    pub fn to_duration<T: ParseHhmmss>(&self) -> Result<T, ParseError> {
+        T::from_parts(self.to_duration_parts())
+    }
+
+    /// Returns the hours, minutes, seconds and frames to display.
+    fn components(&self) -> (u64, u64, u64, u64) {
+        let nominal = self.rate.nominal() as u64;
+        let dropped = self.rate.dropped_per_minute();
+        let mut label = self.frames;
+        if dropped != 0 {
+            // Add back the frame numbers skipped so far: `dropped` in every
+            // minute but the first of each ten-minute block.
+            let per_minute = nominal * 60 - dropped;
+            let per_ten_minutes = nominal * 600 - dropped * 9;
+            let blocks = self.frames / per_ten_minutes;
+            let rest = self.frames % per_ten_minutes;
+            label += dropped * 9 * blocks;
+            if rest >= dropped {
+                label += dropped * ((rest - dropped) / per_minute);
+            }
+        }
+        let seconds = label / nominal;
+        (
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            label % nominal,
+        )
+    }
+}
+
+impl fmt::Display for Timecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (hours, minutes, seconds, frames) = self.components();
+        let separator = if self.rate.drop_frame { ';' } else { ':' };
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{}{:02}",
+            hours, minutes, seconds, separator, frames
+        )
+    }
+}
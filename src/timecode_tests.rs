@@ -0,0 +1,263 @@
+use crate::parse::{DurationParts, Field, ParseError};
+use crate::round::Rounding;
+use crate::timecode::{FrameRate, Timecode};
+use crate::Hhmmss;
+
+#[test]
+fn test_timecode_format() {
+    let tc = Timecode::from_frames(((3600 + 23 * 60 + 45) * 25) + 12, FrameRate::FPS_25);
+    assert_eq!(tc.to_string(), "01:23:45:12");
+    assert_eq!(
+        Timecode::from_frames(0, FrameRate::FPS_60).to_string(),
+        "00:00:00:00"
+    );
+    assert_eq!(
+        Timecode::from_frames(24 * 3600 * 30 + 29, FrameRate::FPS_30).to_string(),
+        "24:00:00:29"
+    );
+    assert_eq!(FrameRate::new(0), None);
+    assert_eq!(FrameRate::new(48).map(|r| r.nominal()), Some(48));
+}
+
+#[test]
+fn test_timecode_from_duration() {
+    let d = std::time::Duration::new(5025, 510_000_000);
+    for (rate, expected, remainder) in [
+        (FrameRate::FPS_24, "01:23:45:12", 10_000_000),
+        (FrameRate::FPS_25, "01:23:45:12", 30_000_000),
+        (FrameRate::FPS_30, "01:23:45:15", 10_000_000),
+        (FrameRate::FPS_50, "01:23:45:25", 10_000_000),
+        (FrameRate::FPS_60, "01:23:45:30", 10_000_000),
+    ] {
+        let (tc, rest) = Timecode::from_duration(&d, rate).unwrap();
+        assert_eq!(tc.to_string(), expected);
+        assert_eq!(rest, DurationParts::new(false, 0, remainder));
+        let start = tc.to_duration_parts().to_nanos() + rest.to_nanos();
+        assert_eq!(start, d.to_duration_parts().to_nanos());
+    }
+
+    // One frame at 24 fps is 41.666… ms; the start is rounded up so the
+    // conversion round-trips.
+    let tc = Timecode::from_frames(1, FrameRate::FPS_24);
+    assert_eq!(
+        tc.to_duration_parts(),
+        DurationParts::new(false, 0, 41_666_667)
+    );
+    let (back, rest) = Timecode::from_duration(&tc.to_duration_parts(), FrameRate::FPS_24).unwrap();
+    assert_eq!(back, tc);
+    assert!(rest.is_zero());
+
+    let d = std::time::Duration::new(0, 20_000_000);
+    for (mode, frames) in [
+        (Rounding::Truncate, 0),
+        (Rounding::HalfUp, 1),
+        (Rounding::HalfEven, 0),
+        (Rounding::Ceil, 1),
+        (Rounding::Floor, 0),
+    ] {
+        let tc = Timecode::from_duration_rounded(&d, FrameRate::FPS_25, mode).unwrap();
+        assert_eq!(tc.frames(), frames, "{:?}", mode);
+    }
+
+    assert_eq!(
+        Timecode::from_duration(&DurationParts::new(true, 1, 0), FrameRate::FPS_25),
+        Err(ParseError::Negative)
+    );
+}
+
+#[test]
+fn test_timecode_parse() {
+    let tc = Timecode::parse("01:23:45:12", FrameRate::FPS_25).unwrap();
+    assert_eq!(tc.frames(), 5025 * 25 + 12);
+    assert_eq!(tc.rate(), FrameRate::FPS_25);
+    assert_eq!(
+        tc.to_duration::<std::time::Duration>(),
+        Ok(std::time::Duration::new(5025, 480_000_000))
+    );
+    assert_eq!(
+        Timecode::parse("100:00:00:59", FrameRate::FPS_60)
+            .unwrap()
+            .to_string(),
+        "100:00:00:59"
+    );
+    assert_eq!(
+        Timecode::parse("01:23:45:25", FrameRate::FPS_25),
+        Err(ParseError::OutOfRange {
+            position: 9,
+            field: Field::Frames,
+            value: 25
+        })
+    );
+    assert_eq!(
+        Timecode::parse("1:23:45:00", FrameRate::FPS_25),
+        Err(ParseError::InvalidWidth {
+            position: 0,
+            field: Field::Hours,
+            width: 1
+        })
+    );
+    assert_eq!(
+        Timecode::parse("01:23:45.00", FrameRate::FPS_25),
+        Err(ParseError::UnexpectedChar {
+            position: 8,
+            found: '.'
+        })
+    );
+    assert_eq!(
+        Timecode::parse("", FrameRate::FPS_25),
+        Err(ParseError::Empty)
+    );
+    assert_eq!(
+        Timecode::parse("307445734561825860:59:00:00", FrameRate::FPS_25),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_timecode_chrono() {
+    let d = chrono::Duration::milliseconds(5_025_500);
+    let (tc, rest) = Timecode::from_duration(&d, FrameRate::FPS_50).unwrap();
+    assert_eq!(tc.to_string(), "01:23:45:25");
+    assert!(rest.is_zero());
+    assert_eq!(tc.to_duration::<chrono::Duration>(), Ok(d));
+    assert_eq!(
+        Timecode::from_duration(&-d, FrameRate::FPS_50),
+        Err(ParseError::Negative)
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_timecode_time() {
+    let d = time::Duration::milliseconds(5_025_500);
+    let tc = Timecode::from_duration_rounded(&d, FrameRate::FPS_24, Rounding::HalfUp).unwrap();
+    assert_eq!(tc.to_string(), "01:23:45:12");
+    assert_eq!(tc.to_duration::<time::Duration>(), Ok(d));
+}
+
+#[test]
+fn test_drop_frame_reference_points() {
+    // SMPTE 12M reference points at 29.97 fps drop-frame.
+    let rate = FrameRate::FPS_29_97_DF;
+    for (frames, label) in [
+        (0, "00:00:00;00"),
+        (1799, "00:00:59;29"),
+        (1800, "00:01:00;02"),
+        (3597, "00:01:59;29"),
+        (3598, "00:02:00;02"),
+        (17_981, "00:09:59;29"),
+        (17_982, "00:10:00;00"),
+        (17_984, "00:10:00;02"),
+        (19_781, "00:10:59;29"),
+        (19_782, "00:11:00;02"),
+        (107_892, "01:00:00;00"),
+        (2_589_407, "23:59:59;29"),
+    ] {
+        let tc = Timecode::from_frames(frames, rate);
+        assert_eq!(tc.to_string(), label);
+        assert_eq!(Timecode::parse(label, rate), Ok(tc));
+    }
+
+    let rate = FrameRate::FPS_59_94_DF;
+    for (frames, label) in [
+        (3599, "00:00:59;59"),
+        (3600, "00:01:00;04"),
+        (35_964, "00:10:00;00"),
+        (215_784, "01:00:00;00"),
+    ] {
+        let tc = Timecode::from_frames(frames, rate);
+        assert_eq!(tc.to_string(), label);
+        assert_eq!(Timecode::parse(label, rate), Ok(tc));
+    }
+}
+
+#[test]
+fn test_drop_frame_durations() {
+    // One drop-frame hour is 107892 frames of 1001/30000 s.
+    let tc = Timecode::parse("01:00:00;00", FrameRate::FPS_29_97_DF).unwrap();
+    assert_eq!(
+        tc.to_duration_parts(),
+        DurationParts::new(false, 3599, 996_400_000)
+    );
+    // Non-drop-frame at the same rate drifts by 3.6 s per hour.
+    let tc = Timecode::parse("01:00:00:00", FrameRate::FPS_29_97).unwrap();
+    assert_eq!(
+        tc.to_duration_parts(),
+        DurationParts::new(false, 3603, 600_000_000)
+    );
+
+    let d = std::time::Duration::new(3600, 0);
+    let (tc, rest) = Timecode::from_duration(&d, FrameRate::FPS_29_97_DF).unwrap();
+    assert_eq!(tc.to_string(), "01:00:00;00");
+    assert_eq!(tc.frames(), 107_892);
+    assert_eq!(
+        tc.to_duration_parts().to_nanos() + rest.to_nanos(),
+        3_600_000_000_000
+    );
+    let tc =
+        Timecode::from_duration_rounded(&d, FrameRate::FPS_59_94_DF, Rounding::HalfUp).unwrap();
+    assert_eq!(tc.to_string(), "01:00:00;00");
+    let d = std::time::Duration::new(3600 + 60, 0);
+    let tc =
+        Timecode::from_duration_rounded(&d, FrameRate::FPS_59_94_DF, Rounding::HalfUp).unwrap();
+    assert_eq!(tc.to_string(), "01:00:59;57");
+
+    // Every frame round-trips through its duration.
+    for frames in (0..40_000).step_by(7) {
+        let tc = Timecode::from_frames(frames, FrameRate::FPS_29_97_DF);
+        let (back, rest) =
+            Timecode::from_duration(&tc.to_duration_parts(), FrameRate::FPS_29_97_DF).unwrap();
+        assert_eq!(back, tc);
+        assert!(rest.is_zero());
+        assert_eq!(
+            Timecode::parse(&tc.to_string(), FrameRate::FPS_29_97_DF),
+            Ok(tc)
+        );
+    }
+}
+
+#[test]
+fn test_drop_frame_errors() {
+    let rate = FrameRate::FPS_29_97_DF;
+    assert_eq!(
+        Timecode::parse("00:01:00;00", rate),
+        Err(ParseError::OutOfRange {
+            position: 9,
+            field: Field::Frames,
+            value: 0
+        })
+    );
+    assert_eq!(
+        Timecode::parse("00:01:00;01", rate),
+        Err(ParseError::OutOfRange {
+            position: 9,
+            field: Field::Frames,
+            value: 1
+        })
+    );
+    assert_eq!(
+        Timecode::parse("00:59:00:02", rate),
+        Err(ParseError::UnexpectedChar {
+            position: 8,
+            found: ':'
+        })
+    );
+    assert_eq!(
+        Timecode::parse("00:01:00;03", FrameRate::FPS_59_94_DF),
+        Err(ParseError::OutOfRange {
+            position: 9,
+            field: Field::Frames,
+            value: 3
+        })
+    );
+    assert_eq!(FrameRate::FPS_29_97.drop_frame(), Some(rate));
+    assert_eq!(FrameRate::FPS_25.drop_frame(), None);
+    assert_eq!(FrameRate::FPS_23_976.drop_frame(), None);
+    assert_eq!(
+        FrameRate::from_ratio(60_000, 1001),
+        Some(FrameRate::FPS_59_94)
+    );
+    assert!(!FrameRate::FPS_59_94.is_drop_frame());
+    assert_eq!(FrameRate::FPS_23_976.nominal(), 24);
+}
@@ -0,0 +1,60 @@
+#![allow(clippy::identity_op)]
+
+use crate::display::TruncationError;
+use crate::format::DurationFormat;
+use crate::{FractPartOfDuration, Hhmmss};
+
+#[test]
+fn test_total_minutes_std() {
+    let d = std::time::Duration::new((1 * 60 + 23) * 60 + 45, 678_901_234);
+    assert_eq!(d.part_of_total_minutes(), 83);
+    assert_eq!(d.part_of_total_minutes_abs(), 83);
+    assert_eq!(d.mmss(), "23:45");
+    assert_eq!(d.total_mmss(), "83:45");
+    assert_eq!(d.total_mmssxxx(), "83:45.678");
+    assert_eq!(d.total_mss(), "83:45");
+    assert_eq!(d.total_mssxxx(), "83:45.678");
+    assert_eq!(
+        d.total_mss_and_fract(FractPartOfDuration::Microseconds),
+        "83:45.678901"
+    );
+    let format = DurationFormat::parse("%m:%S").unwrap();
+    assert_eq!(d.fmt_with(&format), "83:45");
+
+    let d = std::time::Duration::new(3 * 60 + 4, 0);
+    assert_eq!(d.total_mmss(), "03:04");
+    assert_eq!(d.total_mss(), "3:04");
+}
+
+#[test]
+fn test_checked_layouts() {
+    let d = std::time::Duration::new(59 * 60 + 59, 999_000_000);
+    assert_eq!(d.checked_mmss(), Ok("59:59".to_owned()));
+    assert_eq!(d.checked_mssxxx(), Ok("59:59.999".to_owned()));
+
+    let d = std::time::Duration::new(2 * 3600 + 5, 0);
+    assert_eq!(d.checked_mmss(), Err(TruncationError { hours: 2 }));
+    assert_eq!(d.checked_mssxxx(), Err(TruncationError { hours: 2 }));
+    assert_eq!(
+        d.checked_mmss_and_fract(FractPartOfDuration::Nanoseconds),
+        Err(TruncationError { hours: 2 })
+    );
+    assert!(d.display_total_mmss().checked().is_ok());
+    assert!(d.display_hhmmss().checked().is_ok());
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_total_minutes_chrono() {
+    let d = chrono::Duration::seconds(-((1 * 60 + 23) * 60 + 45));
+    assert_eq!(d.part_of_total_minutes(), -83);
+    assert_eq!(d.total_mmss(), "-83:45");
+    assert_eq!(d.checked_mss(), Err(TruncationError { hours: 1 }));
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_total_minutes_time() {
+    let d = time::Duration::new(-((1 * 60 + 23) * 60 + 45), -678_000_000);
+    assert_eq!(d.total_mssxxx(), "-83:45.678");
+}
@@ -0,0 +1,356 @@
+//! WebVTT timestamps and subtitle files.
+//!
+//! WebVTT timestamps are written as "MM:SS.mmm" while the hours are zero and
+//! as "HH:MM:SS.mmm" otherwise. Documents keep their header, cue
+//! identifiers, cue settings and NOTE, STYLE and REGION blocks, and convert
+//! to and from [`Srt`].
+//!
+//! ```
+//! use hhmmss_rs::srt::Srt;
+//! use hhmmss_rs::webvtt::WebVtt;
+//!
+//! let vtt: WebVtt = "WEBVTT\n\n00:01.000 --> 01:00:04.500 align:start\nHello\n"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(
+//!     Srt::from(&vtt).to_string(),
+//!     "1\n00:00:01,000 --> 01:00:04,500\nHello\n"
+//! );
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::parse::{check_sexagesimal, Cursor, DurationParts, Field, ParseError};
+use crate::srt::{self, parse_time_range, Srt, SrtErrorKind};
+use crate::Hhmmss;
+
+/// Parses a WebVTT timestamp such as "01:23:45.678" or "23:45.678" into
+/// [`DurationParts`].
+///
+/// The hours, if present, take two or more digits, the minutes and seconds
+/// exactly two and the milliseconds exactly three. Signs are not accepted.
+pub fn parse_timestamp(s: &str) -> Result<DurationParts, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let mut cursor = Cursor::new(s);
+    let (first_pos, first) = cursor.integer(Field::Minutes)?;
+    let first_width = cursor.pos - first_pos;
+    cursor.expect(':')?;
+    let (second_pos, second) = cursor.two_digits(Field::Seconds)?;
+    let (hours, (minutes_pos, minutes), (seconds_pos, seconds)) = if cursor.eat(':') {
+        if first_width < 2 {
+            return Err(ParseError::InvalidWidth {
+                position: first_pos,
+                field: Field::Hours,
+                width: first_width,
+            });
+        }
+        let third = cursor.two_digits(Field::Seconds)?;
+        (first, (second_pos, second), third)
+    } else {
+        if first_width != 2 {
+            return Err(ParseError::InvalidWidth {
+                position: first_pos,
+                field: Field::Minutes,
+                width: first_width,
+            });
+        }
+        (0, (first_pos, first), (second_pos, second))
+    };
+    check_sexagesimal(minutes_pos, Field::Minutes, minutes)?;
+    check_sexagesimal(seconds_pos, Field::Seconds, seconds)?;
+    cursor.expect('.')?;
+    let millis_pos = cursor.pos;
+    let millis = cursor.digits();
+    if millis.len() != 3 {
+        return Err(if millis.is_empty() {
+            cursor.unexpected()
+        } else {
+            ParseError::InvalidWidth {
+                position: millis_pos,
+                field: Field::Fraction,
+                width: millis.len(),
+            }
+        });
+    }
+    cursor.finish()?;
+    let secs = hours
+        .checked_mul(3600)
+        .and_then(|s| s.checked_add(minutes * 60 + seconds))
+        .ok_or(ParseError::Overflow)?;
+    let millis: u32 = millis.parse().unwrap();
+    Ok(DurationParts::new(false, secs, millis * 1_000_000))
+}
+
+/// A single WebVTT cue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cue {
+    /// The optional line above the time range.
+    pub id: Option<String>,
+    pub start: DurationParts,
+    pub end: DurationParts,
+    /// Everything after the end time, such as "align:start line:0".
+    pub settings: String,
+    /// The lines of the cue payload, joined with "\n". May be empty.
+    pub text: String,
+}
+
+/// A block of a WebVTT document. All blocks but cues are kept verbatim,
+/// including their keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Cue(Cue),
+    Note(String),
+    Style(String),
+    Region(String),
+}
+
+/// A WebVTT document.
+///
+/// Parse it with [`str::parse`] and write it back with its [`Display`]
+/// implementation. Line endings are written as "\n" and the timestamps are
+/// truncated to milliseconds.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebVtt {
+    /// The text after "WEBVTT" on the first line, without the separating
+    /// space.
+    pub title: String,
+    /// The header lines below the first line, such as "Kind: captions".
+    pub header: Vec<String>,
+    pub blocks: Vec<Block>,
+}
+
+/// What went wrong in a [`WebVttError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WebVttErrorKind {
+    /// The document does not start with "WEBVTT".
+    MissingSignature,
+    /// A block is neither a cue nor a NOTE, STYLE or REGION block.
+    UnknownBlock,
+    /// A timestamp could not be parsed. Positions are byte offsets into the
+    /// line.
+    Timestamp(ParseError),
+    /// The cue ends before it starts.
+    EndBeforeStart,
+}
+
+/// An error pointing at the offending line of a [`WebVtt`] document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebVttError {
+    /// The line the error was found on, starting at 1.
+    pub line: usize,
+    pub kind: WebVttErrorKind,
+}
+
+impl fmt::Display for WebVttError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            WebVttErrorKind::MissingSignature => f.write_str("expected \"WEBVTT\""),
+            WebVttErrorKind::UnknownBlock => f.write_str("expected a cue or a NOTE block"),
+            WebVttErrorKind::Timestamp(error) => write!(f, "invalid timestamp: {}", error),
+            WebVttErrorKind::EndBeforeStart => f.write_str("cue ends before it starts"),
+        }
+    }
+}
+
+impl std::error::Error for WebVttError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            WebVttErrorKind::Timestamp(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Returns `true` if `line` is `keyword`, optionally followed by whitespace
+/// and more text.
+fn starts_with_keyword(line: &str, keyword: &str) -> bool {
+    line.strip_prefix(keyword)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+}
+
+fn parse_cue(lines: &[(usize, &str)]) -> Result<Cue, WebVttError> {
+    let (id, (times_line, times)) = match lines {
+        [(_, first), ..] if first.contains("-->") => (None, lines[0]),
+        [(_, first), second, ..] if second.1.contains("-->") => {
+            (Some((*first).to_owned()), *second)
+        }
+        _ => {
+            return Err(WebVttError {
+                line: lines[0].0 + 1,
+                kind: WebVttErrorKind::UnknownBlock,
+            })
+        }
+    };
+    let error = |kind| WebVttError {
+        line: times_line + 1,
+        kind,
+    };
+    let (start, end, settings) = parse_time_range(times, parse_timestamp).map_err(|kind| {
+        error(match kind {
+            SrtErrorKind::Timestamp(error) => WebVttErrorKind::Timestamp(error),
+            SrtErrorKind::EndBeforeStart => WebVttErrorKind::EndBeforeStart,
+            _ => WebVttErrorKind::UnknownBlock,
+        })
+    })?;
+    let payload = &lines[if id.is_some() { 2 } else { 1 }..];
+    Ok(Cue {
+        id,
+        start,
+        end,
+        settings: settings.to_owned(),
+        text: payload
+            .iter()
+            .map(|(_, line)| *line)
+            .collect::<Vec<_>>()
+            .join("\n"),
+    })
+}
+
+impl FromStr for WebVtt {
+    type Err = WebVttError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+        let lines: Vec<_> = s.lines().enumerate().collect();
+        let title = match lines.first() {
+            Some((_, first)) if starts_with_keyword(first, "WEBVTT") => first[6..].trim(),
+            _ => {
+                return Err(WebVttError {
+                    line: 1,
+                    kind: WebVttErrorKind::MissingSignature,
+                })
+            }
+        };
+        let mut blocks_lines = lines[1..].split(|(_, line)| line.trim().is_empty());
+        let header = blocks_lines
+            .next()
+            .unwrap_or_default()
+            .iter()
+            .map(|(_, line)| (*line).to_owned())
+            .collect();
+        let mut blocks = Vec::new();
+        for block in blocks_lines.filter(|block| !block.is_empty()) {
+            let first = block[0].1;
+            let verbatim = || {
+                block
+                    .iter()
+                    .map(|(_, line)| *line)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            blocks.push(if starts_with_keyword(first, "NOTE") {
+                Block::Note(verbatim())
+            } else if starts_with_keyword(first, "STYLE") {
+                Block::Style(verbatim())
+            } else if starts_with_keyword(first, "REGION") {
+                Block::Region(verbatim())
+            } else {
+                Block::Cue(parse_cue(block)?)
+            });
+        }
+        Ok(WebVtt {
+            title: title.to_owned(),
+            header,
+            blocks,
+        })
+    }
+}
+
+impl fmt::Display for WebVtt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WEBVTT")?;
+        if !self.title.is_empty() {
+            write!(f, " {}", self.title)?;
+        }
+        writeln!(f)?;
+        for line in &self.header {
+            writeln!(f, "{}", line)?;
+        }
+        for block in &self.blocks {
+            writeln!(f)?;
+            match block {
+                Block::Cue(cue) => {
+                    if let Some(id) = &cue.id {
+                        writeln!(f, "{}", id)?;
+                    }
+                    write!(
+                        f,
+                        "{} --> {}",
+                        cue.start.display_webvtt(),
+                        cue.end.display_webvtt()
+                    )?;
+                    if !cue.settings.is_empty() {
+                        write!(f, " {}", cue.settings)?;
+                    }
+                    writeln!(f)?;
+                    if !cue.text.is_empty() {
+                        writeln!(f, "{}", cue.text)?;
+                    }
+                }
+                Block::Note(text) | Block::Style(text) | Block::Region(text) => {
+                    writeln!(f, "{}", text)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WebVtt {
+    /// Returns the cues, skipping all other blocks.
+    pub fn cues(&self) -> impl Iterator<Item = &Cue> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Cue(cue) => Some(cue),
+            _ => None,
+        })
+    }
+}
+
+/// Uses the SRT indices as cue identifiers.
+impl From<&Srt> for WebVtt {
+    fn from(srt: &Srt) -> Self {
+        WebVtt {
+            title: String::new(),
+            header: Vec::new(),
+            blocks: srt
+                .cues
+                .iter()
+                .map(|cue| {
+                    Block::Cue(Cue {
+                        id: Some(cue.index.to_string()),
+                        start: cue.start,
+                        end: cue.end,
+                        settings: String::new(),
+                        text: cue.text.clone(),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Numbers the cues from 1 and drops the header, the cue identifiers and
+/// settings and all blocks but cues.
+impl From<&WebVtt> for Srt {
+    fn from(vtt: &WebVtt) -> Self {
+        Srt {
+            cues: vtt
+                .cues()
+                .enumerate()
+                .map(|(i, cue)| srt::Cue {
+                    index: i as u64 + 1,
+                    start: cue.start,
+                    end: cue.end,
+                    text: cue.text.clone(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::parse::{DurationParts, Field, ParseError, ParseHhmmss};
+use crate::srt::Srt;
+use crate::webvtt::{parse_timestamp, Block, WebVtt, WebVttError, WebVttErrorKind};
+use crate::Hhmmss;
+
+const SAMPLE: &str = "WEBVTT - Sample
+Kind: captions
+Language: en
+
+STYLE
+::cue { color: yellow }
+
+NOTE
+This is a comment
+over two lines
+
+intro
+00:01.000 --> 00:04.500 align:start line:0
+Hello, world!
+
+00:59.999 --> 01:00:00.000
+Two
+lines
+";
+
+#[test]
+fn test_fmt_webvtt() {
+    let d = std::time::Duration::new(1425, 678_901_234);
+    assert_eq!(d.fmt_webvtt(), "23:45.678");
+    assert_eq!(
+        std::time::Duration::new(5025, 678_000_000).fmt_webvtt(),
+        "01:23:45.678"
+    );
+    assert_eq!(std::time::Duration::new(0, 0).fmt_webvtt(), "00:00.000");
+}
+
+#[test]
+fn test_parse_webvtt_timestamp() {
+    assert_eq!(
+        parse_timestamp("23:45.678"),
+        Ok(DurationParts::new(false, 1425, 678_000_000))
+    );
+    assert_eq!(
+        std::time::Duration::parse_webvtt("101:23:45.678"),
+        Ok(std::time::Duration::new(365_025, 678_000_000))
+    );
+    assert_eq!(
+        parse_timestamp("1:23:45.678"),
+        Err(ParseError::InvalidWidth {
+            position: 0,
+            field: Field::Hours,
+            width: 1
+        })
+    );
+    assert_eq!(
+        parse_timestamp("123:45.678"),
+        Err(ParseError::InvalidWidth {
+            position: 0,
+            field: Field::Minutes,
+            width: 3
+        })
+    );
+    assert_eq!(
+        parse_timestamp("60:00.000"),
+        Err(ParseError::OutOfRange {
+            position: 0,
+            field: Field::Minutes,
+            value: 60
+        })
+    );
+    assert_eq!(
+        parse_timestamp("00:01,000"),
+        Err(ParseError::UnexpectedChar {
+            position: 5,
+            found: ','
+        })
+    );
+}
+
+#[test]
+fn test_webvtt_round_trip() {
+    let vtt: WebVtt = SAMPLE.parse().unwrap();
+    assert_eq!(vtt.title, "- Sample");
+    assert_eq!(vtt.header, ["Kind: captions", "Language: en"]);
+    assert_eq!(vtt.blocks.len(), 4);
+    assert!(matches!(&vtt.blocks[1], Block::Note(note) if note.ends_with("two lines")));
+    let cues: Vec<_> = vtt.cues().collect();
+    assert_eq!(cues[0].id.as_deref(), Some("intro"));
+    assert_eq!(cues[0].settings, "align:start line:0");
+    assert_eq!(cues[1].id, None);
+    assert_eq!(cues[1].end, DurationParts::new(false, 3600, 0));
+    assert_eq!(cues[1].text, "Two\nlines");
+    assert_eq!(vtt.to_string(), SAMPLE);
+
+    assert_eq!("WEBVTT".parse::<WebVtt>().unwrap().to_string(), "WEBVTT\n");
+}
+
+#[test]
+fn test_webvtt_srt_conversion() {
+    let vtt: WebVtt = SAMPLE.parse().unwrap();
+    let srt = Srt::from(&vtt);
+    assert_eq!(
+        srt.to_string(),
+        "1
+00:00:01,000 --> 00:00:04,500
+Hello, world!
+
+2
+00:00:59,999 --> 01:00:00,000
+Two
+lines
+"
+    );
+    assert_eq!(
+        WebVtt::from(&srt).to_string(),
+        "WEBVTT
+
+1
+00:01.000 --> 00:04.500
+Hello, world!
+
+2
+00:59.999 --> 01:00:00.000
+Two
+lines
+"
+    );
+}
+
+#[test]
+fn test_webvtt_errors() {
+    assert_eq!(
+        "1\n00:01.000 --> 00:02.000\n".parse::<WebVtt>(),
+        Err(WebVttError {
+            line: 1,
+            kind: WebVttErrorKind::MissingSignature
+        })
+    );
+    assert_eq!(
+        "WEBVTTX\n".parse::<WebVtt>().unwrap_err().kind,
+        WebVttErrorKind::MissingSignature
+    );
+    assert_eq!(
+        "WEBVTT\n\nhello\nworld\n".parse::<WebVtt>(),
+        Err(WebVttError {
+            line: 3,
+            kind: WebVttErrorKind::UnknownBlock
+        })
+    );
+    let err = "WEBVTT\n\nid\n00:01.000 --> 00:02.00\n"
+        .parse::<WebVtt>()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        WebVttError {
+            line: 4,
+            kind: WebVttErrorKind::Timestamp(ParseError::InvalidWidth {
+                position: 20,
+                field: Field::Fraction,
+                width: 2
+            })
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "line 4: invalid timestamp: fractional seconds at 20 has 2 digits"
+    );
+    assert_eq!(
+        "WEBVTT\n\n00:03.000 --> 00:02.000\n".parse::<WebVtt>(),
+        Err(WebVttError {
+            line: 3,
+            kind: WebVttErrorKind::EndBeforeStart
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_webvtt_chrono() {
+    let d = chrono::Duration::milliseconds(1_425_678);
+    assert_eq!(d.fmt_webvtt(), "23:45.678");
+    assert_eq!(chrono::Duration::parse_webvtt("23:45.678"), Ok(d));
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_webvtt_time() {
+    let d = time::Duration::milliseconds(5_025_678);
+    assert_eq!(d.fmt_webvtt(), "01:23:45.678");
+    assert_eq!(time::Duration::parse_webvtt("01:23:45.678"), Ok(d));
+}
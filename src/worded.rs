@@ -0,0 +1,281 @@
+//! Human-readable worded durations such as "1 hour 23 minutes" or
+//! "1h 23m 45s".
+//!
+//! ```
+//! use hhmmss_rs::worded::{UnitStyle, WordedFormat};
+//! use hhmmss_rs::Hhmmss as _;
+//!
+//! let d = std::time::Duration::new(5025, 0);
+//! let long = WordedFormat::new(UnitStyle::Long).max_units(2);
+//! assert_eq!(d.fmt_worded(&long), "1 hour 23 minutes");
+//! let narrow = WordedFormat::new(UnitStyle::Narrow);
+//! assert_eq!(d.fmt_worded(&narrow), "1h 23m 45s");
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::fracts::Precision;
+use crate::locale::{Locale, EN};
+use crate::Hhmmss;
+
+/// How unit names are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitStyle {
+    /// "1 hour 23 minutes"
+    Long,
+    /// "1 hr 23 min"
+    Short,
+    /// "1h 23m"
+    Narrow,
+}
+
+/// A unit of a worded duration, from the largest to the smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordUnit {
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl WordUnit {
+    const ALL: [WordUnit; 7] = [
+        WordUnit::Days,
+        WordUnit::Hours,
+        WordUnit::Minutes,
+        WordUnit::Seconds,
+        WordUnit::Milliseconds,
+        WordUnit::Microseconds,
+        WordUnit::Nanoseconds,
+    ];
+
+    /// Returns the length of the unit in nanoseconds.
+    pub fn nanos(&self) -> u128 {
+        match self {
+            WordUnit::Days => 86_400_000_000_000,
+            WordUnit::Hours => 3_600_000_000_000,
+            WordUnit::Minutes => 60_000_000_000,
+            WordUnit::Seconds => 1_000_000_000,
+            WordUnit::Milliseconds => 1_000_000,
+            WordUnit::Microseconds => 1_000,
+            WordUnit::Nanoseconds => 1,
+        }
+    }
+}
+
+/// Options for worded output.
+///
+/// The duration is split into the units from `largest` to `smallest`; the
+/// largest unit absorbs everything above it and anything below the smallest
+/// unit is truncated, unless [`WordedFormat::fract`] writes it as a
+/// fraction. Use [`Hhmmss::rounded`] beforehand to round instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordedFormat {
+    style: UnitStyle,
+    locale: &'static Locale,
+    largest: WordUnit,
+    smallest: WordUnit,
+    max_units: Option<usize>,
+    omit_zero: bool,
+    fract: Option<Precision>,
+    separator: Option<String>,
+}
+
+/// One unit of a split duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Part {
+    pub(crate) unit: WordUnit,
+    pub(crate) count: u128,
+    /// The fractional digits of the smallest unit, trailing zeros removed.
+    pub(crate) fract: u64,
+    pub(crate) places: usize,
+}
+
+impl Part {
+    fn is_zero(&self) -> bool {
+        self.count == 0 && self.fract == 0
+    }
+}
+
+impl WordedFormat {
+    /// Creates a format writing days down to seconds, all non-zero units,
+    /// in English.
+    pub fn new(style: UnitStyle) -> Self {
+        Self {
+            style,
+            locale: &EN,
+            largest: WordUnit::Days,
+            smallest: WordUnit::Seconds,
+            max_units: None,
+            omit_zero: true,
+            fract: None,
+            separator: None,
+        }
+    }
+
+    /// Sets the language, e.g. [`locale::JA`](crate::locale) for "1時間23分".
+    pub fn locale(mut self, locale: &'static Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets the largest unit, e.g. [`WordUnit::Hours`] for "26 hours".
+    pub fn largest(mut self, unit: WordUnit) -> Self {
+        self.largest = unit;
+        self
+    }
+
+    /// Sets the smallest unit, e.g. [`WordUnit::Milliseconds`].
+    pub fn smallest(mut self, unit: WordUnit) -> Self {
+        self.smallest = unit;
+        self
+    }
+
+    /// Writes at most `max_units` units, starting from the largest non-zero
+    /// one.
+    pub fn max_units(mut self, max_units: usize) -> Self {
+        self.max_units = Some(max_units);
+        self
+    }
+
+    /// Whether zero units between non-zero ones are omitted ("1 hour 5
+    /// seconds") or written ("1 hour 0 minutes 5 seconds"). Defaults to
+    /// `true`.
+    pub fn omit_zero(mut self, omit_zero: bool) -> Self {
+        self.omit_zero = omit_zero;
+        self
+    }
+
+    /// Writes the rest below the smallest unit as up to `included`
+    /// fractional digits of it, e.g. "45.678 seconds". Trailing zeros are
+    /// dropped.
+    pub fn fract(mut self, included: impl Into<Precision>) -> Self {
+        self.fract = Some(included.into());
+        self
+    }
+
+    /// Sets the text between units, overriding the locale's.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = Some(separator.to_owned());
+        self
+    }
+
+    /// Returns an adapter writing `duration` in this format.
+    pub fn display<'a, T: Hhmmss + ?Sized>(&'a self, duration: &'a T) -> Worded<'a, T> {
+        Worded {
+            format: self,
+            duration,
+        }
+    }
+
+    /// Formats `duration` into a new `String`.
+    pub fn format<T: Hhmmss + ?Sized>(&self, duration: &T) -> String {
+        self.display(duration).to_string()
+    }
+
+    /// Splits `nanos` into parts, skipping leading zeros.
+    pub(crate) fn split(&self, nanos: u128) -> Vec<Part> {
+        let mut remaining = nanos;
+        let mut parts = Vec::new();
+        for unit in WordUnit::ALL.iter() {
+            if *unit < self.largest || *unit > self.smallest {
+                continue;
+            }
+            let count = remaining / unit.nanos();
+            remaining %= unit.nanos();
+            if count != 0 || !parts.is_empty() {
+                parts.push(Part {
+                    unit: *unit,
+                    count,
+                    fract: 0,
+                    places: 0,
+                });
+            }
+        }
+        let smallest = self.smallest.max(self.largest);
+        if let Some(included) = self.fract {
+            let mut places = included.decimal_places();
+            let mut fract = remaining * 10u128.pow(places as u32) / smallest.nanos();
+            while fract != 0 && fract.is_multiple_of(10) {
+                fract /= 10;
+                places -= 1;
+            }
+            if fract != 0 {
+                if parts.last().map(|part| part.unit) != Some(smallest) {
+                    parts.push(Part {
+                        unit: smallest,
+                        count: 0,
+                        fract: 0,
+                        places: 0,
+                    });
+                }
+                if let Some(last) = parts.last_mut() {
+                    last.fract = fract as u64;
+                    last.places = places;
+                }
+            }
+        }
+        if self.omit_zero {
+            parts.retain(|part| !part.is_zero());
+        }
+        if let Some(max_units) = self.max_units {
+            parts.truncate(max_units.max(1));
+        }
+        if parts.is_empty() {
+            parts.push(Part {
+                unit: smallest,
+                count: 0,
+                fract: 0,
+                places: 0,
+            });
+        }
+        parts
+    }
+}
+
+/// Writes a duration using a [`WordedFormat`].
+pub struct Worded<'a, T: ?Sized> {
+    format: &'a WordedFormat,
+    duration: &'a T,
+}
+
+impl<T: Hhmmss + ?Sized> fmt::Display for Worded<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.duration;
+        let nanos = d.to_duration_parts().to_nanos().unsigned_abs();
+        let format = self.format;
+        let parts = format.split(nanos);
+        let locale = format.locale;
+        let style = locale.style(format.style);
+        let separator = format.separator.as_deref().unwrap_or(style.separator);
+        if d.is_negative() && parts.iter().any(|part| !part.is_zero()) {
+            f.write_char('-')?;
+        }
+        for (i, part) in parts.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(separator)?;
+            }
+            let name = locale.unit_name(part.unit, format.style, part.count, part.fract != 0);
+            if !locale.number_first {
+                write!(f, "{}{}", name, style.spacing)?;
+            }
+            write!(f, "{}", part.count)?;
+            if part.fract != 0 {
+                write!(
+                    f,
+                    "{}{:0width$}",
+                    locale.decimal_separator,
+                    part.fract,
+                    width = part.places
+                )?;
+            }
+            if locale.number_first {
+                write!(f, "{}{}", style.spacing, name)?;
+            }
+        }
+        Ok(())
+    }
+}
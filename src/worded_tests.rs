@@ -0,0 +1,78 @@
+use crate::round::{RoundTo, Rounding};
+use crate::worded::{UnitStyle, WordUnit, WordedFormat};
+use crate::Hhmmss;
+
+#[test]
+fn test_worded_styles() {
+    let d = std::time::Duration::new(5025, 0);
+    let long = WordedFormat::new(UnitStyle::Long);
+    assert_eq!(d.fmt_worded(&long), "1 hour 23 minutes 45 seconds");
+    let short = WordedFormat::new(UnitStyle::Short);
+    assert_eq!(d.fmt_worded(&short), "1 hr 23 min 45 sec");
+    let narrow = WordedFormat::new(UnitStyle::Narrow);
+    assert_eq!(d.fmt_worded(&narrow), "1h 23m 45s");
+    let commas = WordedFormat::new(UnitStyle::Long).separator(", ");
+    assert_eq!(d.fmt_worded(&commas), "1 hour, 23 minutes, 45 seconds");
+}
+
+#[test]
+fn test_worded_plural() {
+    let long = WordedFormat::new(UnitStyle::Long);
+    let d = std::time::Duration::new(86_400 + 3600 + 60 + 1, 0);
+    assert_eq!(d.fmt_worded(&long), "1 day 1 hour 1 minute 1 second");
+    let d = std::time::Duration::new(2 * 86_400, 0);
+    assert_eq!(d.fmt_worded(&long), "2 days");
+    assert_eq!(
+        std::time::Duration::new(0, 0).fmt_worded(&long),
+        "0 seconds"
+    );
+}
+
+#[test]
+fn test_worded_options() {
+    let d = std::time::Duration::new(3600 + 45, 678_000_000);
+    let long = WordedFormat::new(UnitStyle::Long);
+    assert_eq!(d.fmt_worded(&long), "1 hour 45 seconds");
+    assert_eq!(
+        d.fmt_worded(&long.clone().omit_zero(false)),
+        "1 hour 0 minutes 45 seconds"
+    );
+    assert_eq!(
+        d.fmt_worded(&long.clone().omit_zero(false).max_units(2)),
+        "1 hour 0 minutes"
+    );
+    assert_eq!(d.fmt_worded(&long.clone().max_units(1)), "1 hour");
+    let narrow = WordedFormat::new(UnitStyle::Narrow).smallest(WordUnit::Milliseconds);
+    assert_eq!(d.fmt_worded(&narrow), "1h 45s 678ms");
+    let d = std::time::Duration::new(26 * 3600, 0);
+    let hours = WordedFormat::new(UnitStyle::Long).largest(WordUnit::Hours);
+    assert_eq!(d.fmt_worded(&hours), "26 hours");
+    let minutes = WordedFormat::new(UnitStyle::Short).smallest(WordUnit::Minutes);
+    let d = std::time::Duration::new(59, 0);
+    assert_eq!(d.fmt_worded(&minutes), "0 min");
+    assert_eq!(
+        d.rounded(RoundTo::Minutes, Rounding::HalfUp)
+            .fmt_worded(&minutes),
+        "1 min"
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_worded_chrono() {
+    let d = chrono::Duration::seconds(-90);
+    let narrow = WordedFormat::new(UnitStyle::Narrow);
+    assert_eq!(d.fmt_worded(&narrow), "-1m 30s");
+    assert_eq!(d.display_worded(&narrow).to_string(), "-1m 30s");
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_worded_time() {
+    let d = time::Duration::milliseconds(-500);
+    let narrow = WordedFormat::new(UnitStyle::Narrow);
+    assert_eq!(d.fmt_worded(&narrow), "0s");
+    let d = time::Duration::new(3, 5_000);
+    let short = WordedFormat::new(UnitStyle::Short).smallest(WordUnit::Nanoseconds);
+    assert_eq!(d.fmt_worded(&short), "3 sec 5 μs");
+}